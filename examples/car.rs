@@ -0,0 +1,265 @@
+extern crate bevy;
+extern crate bevy_liquidfun;
+
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::dynamics::{
+    b2Body, b2BodyBundle, b2Fixture, b2FixtureDef, b2WheelJoint, b2WheelJointDef, CreateWheelJoint,
+    JointState,
+};
+use bevy_liquidfun::plugins::{LiquidFunDebugDrawPlugin, LiquidFunPlugin};
+use bevy_liquidfun::utils::DebugDrawFixtures;
+use bevy_liquidfun::{
+    collision::b2Shape,
+    dynamics::{b2BodyDef, b2BodyType::Dynamic, b2World},
+};
+
+const MOTOR_SPEED: f32 = 50.;
+const SUSPENSION_FREQUENCY_HZ: f32 = 4.;
+const SUSPENSION_DAMPING_RATIO: f32 = 0.7;
+const WHEEL_RADIUS: f32 = 0.4;
+const WHEEL_DENSITY: f32 = 1.;
+
+#[derive(Component)]
+struct Chassis;
+
+#[derive(Component)]
+struct SuspensionText;
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            LiquidFunPlugin::default(),
+            LiquidFunDebugDrawPlugin,
+        ))
+        .add_systems(Startup, (setup_camera, setup_instructions))
+        .add_systems(
+            Startup,
+            (
+                setup_physics_world,
+                setup_physics_bodies.after(setup_physics_world),
+            ),
+        )
+        .add_systems(Update, (check_keys, follow_car, update_suspension_text))
+        .run();
+}
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn(Camera2dBundle {
+        projection: OrthographicProjection {
+            scale: 0.05,
+            far: 1000.,
+            near: -1000.,
+            ..OrthographicProjection::default()
+        },
+        transform: Transform::from_translation(Vec3::new(0., 10., 0.)),
+        ..Camera2dBundle::default()
+    });
+}
+
+fn setup_instructions(mut commands: Commands) {
+    commands.spawn((
+        TextBundle::from_section(
+            "'A' Drive Left\n'S' Brake\n'D' Drive Right",
+            TextStyle {
+                font_size: 20.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(5.0),
+            left: Val::Px(15.0),
+            ..default()
+        }),
+        SuspensionText,
+    ));
+}
+
+fn setup_physics_world(world: &mut World) {
+    let gravity = Vec2::new(0., -9.81);
+    let b2_world = b2World::new(gravity);
+    world.insert_non_send_resource(b2_world);
+}
+
+fn setup_physics_bodies(mut commands: Commands) {
+    create_ground(&mut commands);
+
+    let chassis_position = Vec2::new(0., 1.);
+    let chassis_entity = create_chassis(&mut commands, chassis_position);
+    let rear_wheel_entity = create_wheel(&mut commands, chassis_position + Vec2::new(-1., -0.65));
+    let front_wheel_entity = create_wheel(&mut commands, chassis_position + Vec2::new(1., -0.6));
+
+    // spring constants for a mass-spring system with the given frequency and damping ratio
+    let wheel_mass = WHEEL_DENSITY * PI * WHEEL_RADIUS * WHEEL_RADIUS;
+    let omega = 2. * PI * SUSPENSION_FREQUENCY_HZ;
+    let joint_def = b2WheelJointDef {
+        local_axis_a: Vec2::Y,
+        enable_limit: true,
+        lower_translation: -0.25,
+        upper_translation: 0.25,
+        enable_motor: true,
+        motor_speed: 0.,
+        stiffness: wheel_mass * omega * omega,
+        damping: 2. * wheel_mass * SUSPENSION_DAMPING_RATIO * omega,
+        ..default()
+    };
+
+    commands
+        .spawn(JointState::default())
+        .add(CreateWheelJoint::new(
+            chassis_entity,
+            rear_wheel_entity,
+            false,
+            &b2WheelJointDef {
+                local_anchor_a: Vec2::new(-1., -0.65),
+                max_motor_torque: 20.,
+                ..joint_def.clone()
+            },
+        ));
+    commands
+        .spawn(JointState::default())
+        .add(CreateWheelJoint::new(
+            chassis_entity,
+            front_wheel_entity,
+            false,
+            &b2WheelJointDef {
+                local_anchor_a: Vec2::new(1., -0.6),
+                max_motor_torque: 10.,
+                ..joint_def
+            },
+        ));
+}
+
+fn create_ground(commands: &mut Commands) {
+    let ground_entity = commands.spawn(b2BodyBundle::default()).id();
+
+    let heights = [0., 0.25, 1., 4., 0., 0., -1., -2., -2., -1.25, 0.];
+    let mut vertices = vec![Vec2::new(-20., 0.), Vec2::new(20., 0.)];
+    vertices.extend(
+        heights
+            .iter()
+            .enumerate()
+            .map(|(i, height)| Vec2::new(25. + 5. * i as f32, *height)),
+    );
+    vertices.extend([Vec2::new(90., 0.), Vec2::new(90., 5.)]);
+    // chain edges only collide on their right side, so the ground has to run from right to left
+    vertices.reverse();
+
+    let shape = b2Shape::Chain {
+        prev_vertex: Vec2::new(90., 6.),
+        next_vertex: Vec2::new(-21., 0.),
+        vertices,
+    };
+    let fixture_def = b2FixtureDef {
+        friction: 0.6,
+        ..b2FixtureDef::new(shape, 0.)
+    };
+    commands.spawn((
+        b2Fixture::new(ground_entity, &fixture_def),
+        DebugDrawFixtures::default_static(),
+    ));
+}
+
+fn create_chassis(commands: &mut Commands, position: Vec2) -> Entity {
+    let body_def = b2BodyDef {
+        body_type: Dynamic,
+        position,
+        ..default()
+    };
+    let chassis_entity = commands.spawn((b2BodyBundle::new(&body_def), Chassis)).id();
+
+    let shape = b2Shape::Polygon {
+        vertices: vec![
+            Vec2::new(-1.5, -0.5),
+            Vec2::new(1.5, -0.5),
+            Vec2::new(1.5, 0.0),
+            Vec2::new(0.0, 0.9),
+            Vec2::new(-1.15, 0.9),
+            Vec2::new(-1.5, 0.2),
+        ],
+    };
+    let fixture_def = b2FixtureDef::new(shape, 1.);
+    commands.spawn((
+        b2Fixture::new(chassis_entity, &fixture_def),
+        DebugDrawFixtures::default_dynamic(),
+    ));
+
+    chassis_entity
+}
+
+fn create_wheel(commands: &mut Commands, position: Vec2) -> Entity {
+    let body_def = b2BodyDef {
+        body_type: Dynamic,
+        position,
+        ..default()
+    };
+    let wheel_entity = commands.spawn(b2BodyBundle::new(&body_def)).id();
+
+    let shape = b2Shape::Circle {
+        radius: WHEEL_RADIUS,
+        position: Vec2::ZERO,
+    };
+    let fixture_def = b2FixtureDef {
+        friction: 0.9,
+        ..b2FixtureDef::new(shape, WHEEL_DENSITY)
+    };
+    commands.spawn((
+        b2Fixture::new(wheel_entity, &fixture_def),
+        DebugDrawFixtures::default_dynamic(),
+    ));
+
+    wheel_entity
+}
+
+fn check_keys(input: Res<Input<KeyCode>>, mut joints: Query<&mut b2WheelJoint>) {
+    let motor_speed = if input.just_pressed(KeyCode::A) {
+        MOTOR_SPEED
+    } else if input.just_pressed(KeyCode::S) {
+        0.
+    } else if input.just_pressed(KeyCode::D) {
+        -MOTOR_SPEED
+    } else {
+        return;
+    };
+
+    for mut joint in joints.iter_mut() {
+        joint.motor_speed = motor_speed;
+    }
+}
+
+fn follow_car(
+    chassis: Query<&b2Body, With<Chassis>>,
+    mut camera: Query<&mut Transform, With<Camera>>,
+) {
+    let Ok(chassis) = chassis.get_single() else {
+        return;
+    };
+    let mut camera = camera.single_mut();
+    camera.translation.x = chassis.position.x;
+}
+
+fn update_suspension_text(
+    joints: Query<&JointState, With<b2WheelJoint>>,
+    mut text: Query<&mut Text, With<SuspensionText>>,
+) {
+    let mut text = text.single_mut();
+    let suspension: Vec<String> = joints
+        .iter()
+        .map(|joint| {
+            format!(
+                "translation {:.2} m, wheel speed {:.1} rad/s",
+                joint.translation(),
+                joint.angular_speed()
+            )
+        })
+        .collect();
+    text.sections[0].value = format!(
+        "'A' Drive Left\n'S' Brake\n'D' Drive Right\n{}",
+        suspension.join("\n")
+    );
+}
@@ -0,0 +1,147 @@
+extern crate bevy;
+extern crate bevy_liquidfun;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::dynamics::{b2BodyBundle, b2Fixture, b2FixtureDef};
+use bevy_liquidfun::plugins::{LiquidFunDebugDrawPlugin, LiquidFunPlugin};
+use bevy_liquidfun::utils::DebugDrawFixtures;
+use bevy_liquidfun::{
+    collision::b2Shape,
+    dynamics::{b2BodyDef, b2BodyType::Dynamic, b2World},
+};
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            LiquidFunPlugin::default(),
+            LiquidFunDebugDrawPlugin,
+        ))
+        .add_systems(Startup, (setup_camera, setup_instructions))
+        .add_systems(
+            Startup,
+            (
+                setup_physics_world,
+                setup_physics_bodies.after(setup_physics_world),
+            ),
+        )
+        .run();
+}
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn(Camera2dBundle {
+        projection: OrthographicProjection {
+            scale: 0.05,
+            far: 1000.,
+            near: -1000.,
+            ..OrthographicProjection::default()
+        },
+        transform: Transform::from_translation(Vec3::new(0., 10., 0.)),
+        ..Camera2dBundle::default()
+    });
+}
+
+fn setup_instructions(mut commands: Commands) {
+    commands.spawn(
+        TextBundle::from_section(
+            "The box slides across the seams of the chain without catching.\n\
+            The circle passes up through the one-sided platform and lands on top of it.",
+            TextStyle {
+                font_size: 20.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(5.0),
+            left: Val::Px(15.0),
+            ..default()
+        }),
+    );
+}
+
+fn setup_physics_world(world: &mut World) {
+    let gravity = Vec2::new(0., -9.81);
+    let b2_world = b2World::new(gravity);
+    world.insert_non_send_resource(b2_world);
+}
+
+fn setup_physics_bodies(mut commands: Commands) {
+    let ground_entity = commands.spawn(b2BodyBundle::default()).id();
+
+    // A flat floor made of many short segments. Without correct ghost vertices the box would
+    // catch on the internal seams. Chain edges are one-sided as well, so the floor runs from
+    // right to left.
+    {
+        let vertices: Vec<Vec2> = (0..=40).map(|i| Vec2::new(20. - i as f32, 0.)).collect();
+        let shape = b2Shape::Chain {
+            vertices,
+            prev_vertex: Vec2::new(21., 0.),
+            next_vertex: Vec2::new(-21., 0.),
+        };
+        let fixture_def = b2FixtureDef::new(shape, 0.);
+        commands.spawn((
+            b2Fixture::new(ground_entity, &fixture_def),
+            DebugDrawFixtures::default_static(),
+        ));
+    }
+
+    // A platform that only collides from above. Its normal points to the right when looking
+    // from v1 to v2, so the vertices run from right to left.
+    {
+        let shape = b2Shape::EdgeOneSided {
+            v0: Vec2::new(8., 8.),
+            v1: Vec2::new(6., 8.),
+            v2: Vec2::new(2., 8.),
+            v3: Vec2::new(0., 8.),
+        };
+        let fixture_def = b2FixtureDef::new(shape, 0.);
+        commands.spawn((
+            b2Fixture::new(ground_entity, &fixture_def),
+            DebugDrawFixtures::default_static(),
+        ));
+    }
+
+    {
+        let body_def = b2BodyDef {
+            body_type: Dynamic,
+            position: Vec2::new(-18., 0.5),
+            ..default()
+        };
+        let mut body_bundle = b2BodyBundle::new(&body_def);
+        body_bundle.body.linear_velocity = Vec2::new(12., 0.);
+        let box_entity = commands.spawn(body_bundle).id();
+
+        let fixture_def = b2FixtureDef {
+            friction: 0.1,
+            ..b2FixtureDef::new(b2Shape::create_box(0.5, 0.5), 1.)
+        };
+        commands.spawn((
+            b2Fixture::new(box_entity, &fixture_def),
+            DebugDrawFixtures::default_dynamic(),
+        ));
+    }
+
+    {
+        let body_def = b2BodyDef {
+            body_type: Dynamic,
+            position: Vec2::new(4., 1.),
+            ..default()
+        };
+        let mut body_bundle = b2BodyBundle::new(&body_def);
+        body_bundle.body.linear_velocity = Vec2::new(0., 15.);
+        let circle_entity = commands.spawn(body_bundle).id();
+
+        let shape = b2Shape::Circle {
+            radius: 0.5,
+            position: Vec2::ZERO,
+        };
+        let fixture_def = b2FixtureDef::new(shape, 1.);
+        commands.spawn((
+            b2Fixture::new(circle_entity, &fixture_def),
+            DebugDrawFixtures::default_dynamic(),
+        ));
+    }
+}
@@ -0,0 +1,119 @@
+extern crate bevy;
+extern crate bevy_liquidfun;
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use bevy_liquidfun::dynamics::{b2BodyBundle, b2ContactFilter, b2Fixture, b2FixtureDef};
+use bevy_liquidfun::plugins::{LiquidFunDebugDrawPlugin, LiquidFunPlugin};
+use bevy_liquidfun::utils::DebugDrawFixtures;
+use bevy_liquidfun::{
+    collision::b2Shape,
+    dynamics::{b2BodyDef, b2BodyType::Dynamic, b2World},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Team {
+    Red,
+    Blue,
+}
+
+/// Bodies of the same team pass through each other, but still collide with the other team and
+/// with the ground.
+struct TeamFilter {
+    teams: HashMap<Entity, Team>,
+}
+
+impl b2ContactFilter for TeamFilter {
+    fn should_collide(
+        &mut self,
+        _fixture_a: Entity,
+        body_a: Entity,
+        _fixture_b: Entity,
+        body_b: Entity,
+    ) -> bool {
+        match (self.teams.get(&body_a), self.teams.get(&body_b)) {
+            (Some(team_a), Some(team_b)) => team_a != team_b,
+            _ => true,
+        }
+    }
+}
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            LiquidFunPlugin::default(),
+            LiquidFunDebugDrawPlugin,
+        ))
+        .add_systems(Startup, setup_camera)
+        .add_systems(
+            Startup,
+            (
+                setup_physics_world,
+                setup_physics_bodies.after(setup_physics_world),
+            ),
+        )
+        .run();
+}
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn(Camera2dBundle {
+        projection: OrthographicProjection {
+            scale: 0.05,
+            far: 1000.,
+            near: -1000.,
+            ..OrthographicProjection::default()
+        },
+        transform: Transform::from_translation(Vec3::new(0., 10., 0.)),
+        ..Camera2dBundle::default()
+    });
+}
+
+fn setup_physics_world(world: &mut World) {
+    let gravity = Vec2::new(0., -9.81);
+    let b2_world = b2World::new(gravity);
+    world.insert_non_send_resource(b2_world);
+}
+
+fn setup_physics_bodies(mut commands: Commands, mut b2_world: NonSendMut<b2World>) {
+    let ground_entity = commands.spawn(b2BodyBundle::default()).id();
+
+    let shape = b2Shape::EdgeTwoSided {
+        v1: Vec2::new(-40., 0.),
+        v2: Vec2::new(40., 0.),
+    };
+    let fixture_def = b2FixtureDef::new(shape, 0.);
+    commands.spawn((
+        b2Fixture::new(ground_entity, &fixture_def),
+        DebugDrawFixtures::default_static(),
+    ));
+
+    let mut teams = HashMap::new();
+    let circle_shape = b2Shape::Circle {
+        radius: 1.,
+        position: Vec2::ZERO,
+    };
+    let fixture_def = b2FixtureDef::new(circle_shape, 1.);
+    for i in 0..10 {
+        let team = if i % 2 == 0 { Team::Red } else { Team::Blue };
+        let body_def = b2BodyDef {
+            body_type: Dynamic,
+            position: Vec2::new(0.2 * i as f32, 4. + 3. * i as f32),
+            ..default()
+        };
+        let body_entity = commands.spawn(b2BodyBundle::new(&body_def)).id();
+        teams.insert(body_entity, team);
+
+        let color = match team {
+            Team::Red => Color::RED,
+            Team::Blue => Color::BLUE,
+        };
+        commands.spawn((
+            b2Fixture::new(body_entity, &fixture_def),
+            DebugDrawFixtures::splat(color),
+        ));
+    }
+
+    b2_world.set_contact_filter(TeamFilter { teams });
+}
@@ -0,0 +1,105 @@
+extern crate bevy;
+extern crate bevy_liquidfun;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::dynamics::{b2BodyBundle, b2Fixture, b2FixtureDef, b2PreSolveContact};
+use bevy_liquidfun::plugins::{LiquidFunDebugDrawPlugin, LiquidFunPlugin};
+use bevy_liquidfun::utils::DebugDrawFixtures;
+use bevy_liquidfun::{
+    collision::b2Shape,
+    dynamics::{b2BodyDef, b2BodyType::Dynamic, b2World},
+};
+
+const BELT_SPEED: f32 = 5.;
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            LiquidFunPlugin::default(),
+            LiquidFunDebugDrawPlugin,
+        ))
+        .add_systems(Startup, setup_camera)
+        .add_systems(
+            Startup,
+            (
+                setup_physics_world,
+                setup_physics_bodies.after(setup_physics_world),
+            ),
+        )
+        .run();
+}
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn(Camera2dBundle {
+        projection: OrthographicProjection {
+            scale: 0.05,
+            far: 1000.,
+            near: -1000.,
+            ..OrthographicProjection::default()
+        },
+        transform: Transform::from_translation(Vec3::new(0., 10., 0.)),
+        ..Camera2dBundle::default()
+    });
+}
+
+fn setup_physics_world(world: &mut World) {
+    let gravity = Vec2::new(0., -9.81);
+    let b2_world = b2World::new(gravity);
+    world.insert_non_send_resource(b2_world);
+}
+
+fn setup_physics_bodies(mut commands: Commands, mut b2_world: NonSendMut<b2World>) {
+    let ground_entity = commands.spawn(b2BodyBundle::default()).id();
+
+    let shape = b2Shape::EdgeTwoSided {
+        v1: Vec2::new(-40., 0.),
+        v2: Vec2::new(40., 0.),
+    };
+    let fixture_def = b2FixtureDef::new(shape, 0.);
+    commands.spawn((
+        b2Fixture::new(ground_entity, &fixture_def),
+        DebugDrawFixtures::default_static(),
+    ));
+
+    let belt_shape = b2Shape::create_box_with_offset(10., 0.5, Vec2::new(-5., 8.));
+    let belt_def = b2FixtureDef {
+        friction: 0.8,
+        ..b2FixtureDef::new(belt_shape, 0.)
+    };
+    let belt_entity = commands
+        .spawn((
+            b2Fixture::new(ground_entity, &belt_def),
+            DebugDrawFixtures::default_static(),
+        ))
+        .id();
+
+    // The tangent speed is relative to the contact's tangent direction, which flips with the
+    // order of the fixtures in the contact.
+    b2_world.add_pre_solve_hook(move |contact: &mut b2PreSolveContact| {
+        if contact.fixture_a() == belt_entity {
+            contact.set_tangent_speed(BELT_SPEED);
+        } else if contact.fixture_b() == belt_entity {
+            contact.set_tangent_speed(-BELT_SPEED);
+        }
+    });
+
+    let box_shape = b2Shape::create_box(0.5, 0.5);
+    let box_def = b2FixtureDef {
+        friction: 0.8,
+        ..b2FixtureDef::new(box_shape, 1.)
+    };
+    for i in 0..5 {
+        let body_def = b2BodyDef {
+            body_type: Dynamic,
+            position: Vec2::new(-12. + 2. * i as f32, 10.),
+            ..default()
+        };
+        let box_entity = commands.spawn(b2BodyBundle::new(&body_def)).id();
+        commands.spawn((
+            b2Fixture::new(box_entity, &box_def),
+            DebugDrawFixtures::default_dynamic(),
+        ));
+    }
+}
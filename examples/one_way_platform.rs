@@ -0,0 +1,118 @@
+extern crate bevy;
+extern crate bevy_liquidfun;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::dynamics::{b2BodyBundle, b2Fixture, b2FixtureDef, OneWayPlatform};
+use bevy_liquidfun::plugins::{LiquidFunDebugDrawPlugin, LiquidFunPlugin};
+use bevy_liquidfun::utils::DebugDrawFixtures;
+use bevy_liquidfun::{
+    collision::b2Shape,
+    dynamics::{b2BodyDef, b2BodyType::Dynamic, b2World},
+};
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            LiquidFunPlugin::default(),
+            LiquidFunDebugDrawPlugin,
+        ))
+        .add_systems(Startup, (setup_camera, setup_instructions))
+        .add_systems(
+            Startup,
+            (
+                setup_physics_world,
+                setup_physics_bodies.after(setup_physics_world),
+            ),
+        )
+        .add_systems(Update, check_launch_key)
+        .run();
+}
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn(Camera2dBundle {
+        projection: OrthographicProjection {
+            scale: 0.05,
+            far: 1000.,
+            near: -1000.,
+            ..OrthographicProjection::default()
+        },
+        transform: Transform::from_translation(Vec3::new(0., 10., 0.)),
+        ..Camera2dBundle::default()
+    });
+}
+
+fn setup_instructions(mut commands: Commands) {
+    commands.spawn(
+        TextBundle::from_section(
+            "Press Space to launch a ball up through the platforms",
+            TextStyle {
+                font_size: 20.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(5.0),
+            left: Val::Px(15.0),
+            ..default()
+        }),
+    );
+}
+
+fn setup_physics_world(world: &mut World) {
+    let gravity = Vec2::new(0., -9.81);
+    let b2_world = b2World::new(gravity);
+    world.insert_non_send_resource(b2_world);
+}
+
+fn setup_physics_bodies(mut commands: Commands) {
+    let ground_entity = commands.spawn(b2BodyBundle::default()).id();
+
+    let shape = b2Shape::EdgeTwoSided {
+        v1: Vec2::new(-40., 0.),
+        v2: Vec2::new(40., 0.),
+    };
+    let fixture_def = b2FixtureDef::new(shape, 0.);
+    commands.spawn((
+        b2Fixture::new(ground_entity, &fixture_def),
+        DebugDrawFixtures::default_static(),
+    ));
+
+    for i in 0..3 {
+        let shape = b2Shape::create_box_with_offset(4., 0.25, Vec2::new(0., 5. + 5. * i as f32));
+        let fixture_def = b2FixtureDef::new(shape, 0.);
+        commands.spawn((
+            b2Fixture::new(ground_entity, &fixture_def),
+            OneWayPlatform::default(),
+            DebugDrawFixtures::default_static(),
+        ));
+    }
+}
+
+fn check_launch_key(input: Res<Input<KeyCode>>, mut commands: Commands) {
+    if !input.just_pressed(KeyCode::Space) {
+        return;
+    }
+
+    let body_def = b2BodyDef {
+        body_type: Dynamic,
+        position: Vec2::new(0., 1.),
+        ..default()
+    };
+    let mut body_bundle = b2BodyBundle::new(&body_def);
+    body_bundle.body.linear_velocity = Vec2::new(0., 20.);
+    let ball_entity = commands.spawn(body_bundle).id();
+
+    let shape = b2Shape::Circle {
+        radius: 0.5,
+        position: Vec2::ZERO,
+    };
+    let fixture_def = b2FixtureDef::new(shape, 1.);
+    commands.spawn((
+        b2Fixture::new(ball_entity, &fixture_def),
+        DebugDrawFixtures::default_dynamic(),
+    ));
+}
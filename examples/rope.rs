@@ -0,0 +1,130 @@
+extern crate bevy;
+extern crate bevy_liquidfun;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::dynamics::{
+    b2BodyBundle, b2Fixture, b2FixtureDef, Rope, RopeBuilder, RopeJointType,
+};
+use bevy_liquidfun::plugins::{LiquidFunDebugDrawPlugin, LiquidFunPlugin};
+use bevy_liquidfun::utils::DebugDrawFixtures;
+use bevy_liquidfun::{
+    collision::b2Shape,
+    dynamics::{b2BodyDef, b2BodyType::Dynamic, b2World},
+};
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            LiquidFunPlugin::default(),
+            LiquidFunDebugDrawPlugin,
+        ))
+        .add_systems(Startup, (setup_camera, setup_instructions))
+        .add_systems(
+            Startup,
+            (
+                setup_physics_world,
+                setup_physics_bodies.after(setup_physics_world),
+            ),
+        )
+        .add_systems(Update, check_keys)
+        .run();
+}
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn(Camera2dBundle {
+        projection: OrthographicProjection {
+            scale: 0.05,
+            far: 1000.,
+            near: -1000.,
+            ..OrthographicProjection::default()
+        },
+        transform: Transform::from_translation(Vec3::new(0., 10., 0.)),
+        ..Camera2dBundle::default()
+    });
+}
+
+fn setup_instructions(mut commands: Commands) {
+    commands.spawn(
+        TextBundle::from_section(
+            "'D' Despawn Ropes",
+            TextStyle {
+                font_size: 20.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(5.0),
+            left: Val::Px(15.0),
+            ..default()
+        }),
+    );
+}
+
+fn setup_physics_world(world: &mut World) {
+    let gravity = Vec2::new(0., -9.81);
+    let b2_world = b2World::new(gravity);
+    world.insert_non_send_resource(b2_world);
+}
+
+fn setup_physics_bodies(mut commands: Commands) {
+    let ground_entity = commands.spawn(b2BodyBundle::default()).id();
+    let shape = b2Shape::EdgeTwoSided {
+        v1: Vec2::new(-40., 0.),
+        v2: Vec2::new(40., 0.),
+    };
+    let fixture_def = b2FixtureDef::new(shape, 0.);
+    commands.spawn((
+        b2Fixture::new(ground_entity, &fixture_def),
+        DebugDrawFixtures::default_static(),
+    ));
+
+    // a rope hanging from the ceiling with a heavy box at its end
+    let box_position = Vec2::new(-5., 20.);
+    let box_entity = create_box(&mut commands, box_position);
+    RopeBuilder::new(Vec2::new(-15., 20.), box_position)
+        .segment_count(20)
+        .attach_start(ground_entity, Vec2::new(-15., 20.))
+        .attach_end(box_entity, Vec2::ZERO)
+        .debug_draw(DebugDrawFixtures::default_dynamic())
+        .spawn(&mut commands);
+
+    // a slack rope strung between two points
+    RopeBuilder::new(Vec2::new(2., 15.), Vec2::new(18., 15.))
+        .segment_count(30)
+        .thickness(0.2)
+        .joint_type(RopeJointType::Distance { max_length: 0.05 })
+        .attach_start(ground_entity, Vec2::new(2., 15.))
+        .attach_end(ground_entity, Vec2::new(18., 15.))
+        .debug_draw(DebugDrawFixtures::splat(Color::BEIGE))
+        .spawn(&mut commands);
+}
+
+fn create_box(commands: &mut Commands, position: Vec2) -> Entity {
+    let body_def = b2BodyDef {
+        body_type: Dynamic,
+        position,
+        ..default()
+    };
+    let box_entity = commands.spawn(b2BodyBundle::new(&body_def)).id();
+
+    let box_shape = b2Shape::create_box(1.0, 1.0);
+    let fixture_def = b2FixtureDef::new(box_shape, 5.);
+    commands.spawn((
+        b2Fixture::new(box_entity, &fixture_def),
+        DebugDrawFixtures::default_dynamic(),
+    ));
+
+    box_entity
+}
+
+fn check_keys(input: Res<Input<KeyCode>>, mut commands: Commands, ropes: Query<&Rope>) {
+    if input.just_pressed(KeyCode::D) {
+        for rope in ropes.iter() {
+            commands.entity(rope.root()).despawn_recursive();
+        }
+    }
+}
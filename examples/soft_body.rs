@@ -0,0 +1,136 @@
+extern crate bevy;
+extern crate bevy_liquidfun;
+
+use bevy::prelude::*;
+use bevy::sprite::MaterialMesh2dBundle;
+
+use bevy_liquidfun::dynamics::{
+    b2BodyBundle, b2Fixture, b2FixtureDef, SoftBody, SoftBodyBuilder, SoftBodyShape,
+};
+use bevy_liquidfun::plugins::{LiquidFunDebugDrawPlugin, LiquidFunPlugin};
+use bevy_liquidfun::utils::DebugDrawFixtures;
+use bevy_liquidfun::{collision::b2Shape, dynamics::b2World};
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            LiquidFunPlugin::default(),
+            LiquidFunDebugDrawPlugin,
+        ))
+        .add_systems(Startup, (setup_camera, setup_instructions))
+        .add_systems(
+            Startup,
+            (
+                setup_physics_world,
+                setup_physics_bodies.after(setup_physics_world),
+            ),
+        )
+        .add_systems(Update, check_keys)
+        .run();
+}
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn(Camera2dBundle {
+        projection: OrthographicProjection {
+            scale: 0.05,
+            far: 1000.,
+            near: -1000.,
+            ..OrthographicProjection::default()
+        },
+        transform: Transform::from_translation(Vec3::new(0., 10., 0.)),
+        ..Camera2dBundle::default()
+    });
+}
+
+fn setup_instructions(mut commands: Commands) {
+    commands.spawn(
+        TextBundle::from_section(
+            "'P' Increase Blob Pressure\n'O' Decrease Blob Pressure\n'D' Despawn Soft Bodies",
+            TextStyle {
+                font_size: 20.0,
+                color: Color::WHITE,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(5.0),
+            left: Val::Px(15.0),
+            ..default()
+        }),
+    );
+}
+
+fn setup_physics_world(world: &mut World) {
+    let gravity = Vec2::new(0., -9.81);
+    let b2_world = b2World::new(gravity);
+    world.insert_non_send_resource(b2_world);
+}
+
+fn setup_physics_bodies(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let ground_entity = commands.spawn(b2BodyBundle::default()).id();
+    let shape = b2Shape::EdgeTwoSided {
+        v1: Vec2::new(-40., 0.),
+        v2: Vec2::new(40., 0.),
+    };
+    let fixture_def = b2FixtureDef::new(shape, 0.);
+    commands.spawn((
+        b2Fixture::new(ground_entity, &fixture_def),
+        DebugDrawFixtures::default_static(),
+    ));
+
+    let blob = SoftBodyBuilder::new(SoftBodyShape::Ring {
+        center: Vec2::new(-6., 10.),
+        radius: 3.,
+        node_count: 24,
+    })
+    .stiffness(50.)
+    .pressure(5.)
+    .debug_draw(DebugDrawFixtures::default_dynamic());
+    let blob_mesh = meshes.add(blob.build_mesh());
+    let blob = blob.spawn(&mut commands);
+    commands.entity(blob.root()).insert(MaterialMesh2dBundle {
+        mesh: blob_mesh.into(),
+        material: materials.add(ColorMaterial::from(Color::SEA_GREEN)),
+        ..default()
+    });
+
+    let jelly = SoftBodyBuilder::new(SoftBodyShape::Grid {
+        center: Vec2::new(6., 12.),
+        size: Vec2::new(5., 3.),
+        columns: 6,
+        rows: 4,
+    })
+    .stiffness(80.)
+    .debug_draw(DebugDrawFixtures::default_dynamic());
+    let jelly_mesh = meshes.add(jelly.build_mesh());
+    let jelly = jelly.spawn(&mut commands);
+    commands.entity(jelly.root()).insert(MaterialMesh2dBundle {
+        mesh: jelly_mesh.into(),
+        material: materials.add(ColorMaterial::from(Color::CRIMSON)),
+        ..default()
+    });
+}
+
+fn check_keys(
+    input: Res<Input<KeyCode>>,
+    mut commands: Commands,
+    mut soft_bodies: Query<&mut SoftBody>,
+) {
+    for mut soft_body in soft_bodies.iter_mut() {
+        if input.just_pressed(KeyCode::P) {
+            soft_body.pressure += 1.;
+        }
+        if input.just_pressed(KeyCode::O) {
+            soft_body.pressure = (soft_body.pressure - 1.).max(0.);
+        }
+        if input.just_pressed(KeyCode::D) {
+            commands.entity(soft_body.root()).despawn_recursive();
+        }
+    }
+}
@@ -17,9 +17,21 @@ pub enum b2Shape {
         v1: Vec2,
         v2: Vec2,
     },
+    /// An edge that only collides on its right side, looking from `v1` to `v2`. The ghost
+    /// vertices `v0` (preceding the edge) and `v3` (following it) provide smooth movement
+    /// across junctions with neighbouring edges.
+    EdgeOneSided {
+        v0: Vec2,
+        v1: Vec2,
+        v2: Vec2,
+        v3: Vec2,
+    },
     Polygon {
         vertices: Vec<Vec2>,
     },
+    /// An open chain of edges. `prev_vertex` precedes the first vertex and `next_vertex`
+    /// follows the last one; they are used as ghost vertices for smooth collision at the ends.
+    /// Like [`b2Shape::EdgeOneSided`], each edge only collides on its right side.
     Chain {
         vertices: Vec<Vec2>,
         prev_vertex: Vec2,
@@ -71,6 +83,7 @@ impl b2Shape {
         match self {
             b2Shape::Circle { radius, position } => circle_to_ffi(*radius, *position),
             b2Shape::EdgeTwoSided { v1, v2 } => edge_to_ffi(*v1, *v2),
+            b2Shape::EdgeOneSided { v0, v1, v2, v3 } => edge_one_sided_to_ffi(*v0, *v1, *v2, *v3),
             b2Shape::Polygon { vertices } => polygon_to_ffi(vertices),
             b2Shape::Chain {
                 vertices,
@@ -82,6 +95,76 @@ impl b2Shape {
     }
 }
 
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct b2AABB {
+    pub lower_bound: Vec2,
+    pub upper_bound: Vec2,
+}
+
+impl b2AABB {
+    pub fn center(&self) -> Vec2 {
+        0.5 * (self.lower_bound + self.upper_bound)
+    }
+
+    pub fn extents(&self) -> Vec2 {
+        0.5 * (self.upper_bound - self.lower_bound)
+    }
+
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        point.cmpge(self.lower_bound).all() && point.cmple(self.upper_bound).all()
+    }
+
+    pub fn overlaps(&self, other: &b2AABB) -> bool {
+        self.lower_bound.cmple(other.upper_bound).all()
+            && other.lower_bound.cmple(self.upper_bound).all()
+    }
+
+    pub fn combine(&self, other: &b2AABB) -> b2AABB {
+        b2AABB {
+            lower_bound: self.lower_bound.min(other.lower_bound),
+            upper_bound: self.upper_bound.max(other.upper_bound),
+        }
+    }
+
+    pub(crate) fn from_ffi(aabb: &ffi::b2AABB) -> Self {
+        b2AABB {
+            lower_bound: to_Vec2(&aabb.lowerBound),
+            upper_bound: to_Vec2(&aabb.upperBound),
+        }
+    }
+
+    pub(crate) fn to_ffi(&self) -> ffi::b2AABB {
+        ffi::b2AABB {
+            lowerBound: to_b2Vec2(&self.lower_bound),
+            upperBound: to_b2Vec2(&self.upper_bound),
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct b2MassData {
+    /// The mass of the shape, usually in kilograms.
+    pub mass: f32,
+
+    /// The position of the shape's centroid relative to the shape's origin.
+    pub center: Vec2,
+
+    /// The rotational inertia of the shape about the local origin.
+    pub rotational_inertia: f32,
+}
+
+impl b2MassData {
+    pub(crate) fn from_ffi(mass_data: &ffi::b2MassData) -> Self {
+        b2MassData {
+            mass: mass_data.mass,
+            center: to_Vec2(&mass_data.center),
+            rotational_inertia: mass_data.I,
+        }
+    }
+}
+
 impl Default for b2Shape {
     fn default() -> Self {
         Self::Circle {
@@ -116,6 +199,22 @@ fn edge_to_ffi<'a>(v1: Vec2, v2: Vec2) -> &'a ffi::b2Shape {
     }
 }
 
+fn edge_one_sided_to_ffi<'a>(v0: Vec2, v1: Vec2, v2: Vec2, v3: Vec2) -> &'a ffi::b2Shape {
+    let mut shape = ffi::b2EdgeShape::new().within_unique_ptr();
+    shape.pin_mut().SetOneSided(
+        &to_b2Vec2(&v0),
+        &to_b2Vec2(&v1),
+        &to_b2Vec2(&v2),
+        &to_b2Vec2(&v3),
+    );
+
+    let shape_ptr = shape.into_raw();
+    unsafe {
+        let ffi_shape: &ffi::b2Shape = shape_ptr.as_ref().unwrap().as_ref();
+        return ffi_shape;
+    }
+}
+
 fn polygon_to_ffi<'a>(vertices: &Vec<Vec2>) -> &'a ffi::b2Shape {
     let mut shape = ffi::b2PolygonShape::new().within_unique_ptr();
     let vertices: Vec<b2Vec2> = vertices.iter().map(|v| to_b2Vec2(v)).collect();
@@ -136,8 +235,8 @@ fn polygon_to_ffi<'a>(vertices: &Vec<Vec2>) -> &'a ffi::b2Shape {
 
 fn chain_to_ffi<'a>(
     vertices: &Vec<Vec2>,
-    next_vertex: Vec2,
     prev_vertex: Vec2,
+    next_vertex: Vec2,
 ) -> &'a ffi::b2Shape {
     let mut shape = ffi::b2ChainShape::new().within_unique_ptr();
     let vertices: Vec<b2Vec2> = vertices.iter().map(|v| to_b2Vec2(v)).collect();
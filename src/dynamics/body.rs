@@ -218,3 +218,29 @@ impl Default for GravityScale {
 impl GravityScale {
     pub const ZERO: Self = Self(0.);
 }
+
+/// Extra forces for a body floating in a particle system, computed from the body's
+/// [`b2ParticleContacts`](crate::dynamics::b2ParticleContacts), which must be present on the
+/// same entity. LiquidFun already pushes bodies out of particles; this adds controllable lift
+/// and drag on top so floating bodies settle instead of bobbing forever.
+#[derive(Component, Debug, Copy, Clone)]
+pub struct FluidInteraction {
+    /// Lift against gravity per unit of displaced fluid mass, applied at the touching particles
+    /// so that a partially submerged body also rights itself. Each touching particle displaces
+    /// its system's density times its diameter squared, weighted by how deep it touches.
+    pub buoyancy: f32,
+    /// Opposes the body's linear velocity, scaled by how many particles touch it.
+    pub linear_drag: f32,
+    /// Opposes the body's angular velocity, scaled by how many particles touch it.
+    pub angular_drag: f32,
+}
+
+impl Default for FluidInteraction {
+    fn default() -> Self {
+        Self {
+            buoyancy: 1.,
+            linear_drag: 0.5,
+            angular_drag: 0.5,
+        }
+    }
+}
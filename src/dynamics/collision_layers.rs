@@ -0,0 +1,122 @@
+use bevy::prelude::{Component, Resource};
+
+use crate::dynamics::b2Filter;
+
+/// A named collision layer, usually implemented by a fieldless enum (`Player`, `Enemy`,
+/// `Terrain`, ...) whose `index` returns `self as u8`.
+pub trait PhysicsLayer: Copy {
+    /// The bit index of the layer, between 0 and 15.
+    fn index(self) -> u8;
+
+    fn bit(self) -> u16 {
+        assert!(self.index() < 16, "Box2D only supports 16 collision layers");
+        1 << self.index()
+    }
+}
+
+fn layers_to_bits<L: PhysicsLayer>(layers: impl IntoIterator<Item = L>) -> u16 {
+    layers.into_iter().fold(0, |bits, layer| bits | layer.bit())
+}
+
+/// The layers a fixture belongs to. Expanded into the fixture's [`b2Filter`] using the
+/// [`CollisionLayerMatrix`], both when the fixture is created and whenever this component or the
+/// matrix changes.
+#[derive(Component, Debug, Copy, Clone, Default)]
+pub struct CollisionLayers {
+    memberships: u16,
+    filters: Option<u16>,
+}
+
+impl CollisionLayers {
+    pub fn new<L: PhysicsLayer>(memberships: impl IntoIterator<Item = L>) -> Self {
+        Self {
+            memberships: layers_to_bits(memberships),
+            filters: None,
+        }
+    }
+
+    /// Overrides the layers this fixture collides with, instead of deriving them from the
+    /// [`CollisionLayerMatrix`].
+    pub fn with_filters<L: PhysicsLayer>(mut self, filters: impl IntoIterator<Item = L>) -> Self {
+        self.filters = Some(layers_to_bits(filters));
+        self
+    }
+
+    pub fn memberships(&self) -> u16 {
+        self.memberships
+    }
+
+    pub fn contains<L: PhysicsLayer>(&self, layer: L) -> bool {
+        self.memberships & layer.bit() != 0
+    }
+
+    pub fn add<L: PhysicsLayer>(&mut self, layer: L) -> &mut Self {
+        self.memberships |= layer.bit();
+        self
+    }
+
+    pub fn remove<L: PhysicsLayer>(&mut self, layer: L) -> &mut Self {
+        self.memberships &= !layer.bit();
+        self
+    }
+
+    pub(crate) fn to_filter(&self, matrix: &CollisionLayerMatrix, group_index: i16) -> b2Filter {
+        b2Filter {
+            category: self.memberships,
+            mask: self
+                .filters
+                .unwrap_or_else(|| matrix.mask(self.memberships)),
+            group_index,
+        }
+    }
+}
+
+impl From<CollisionLayers> for u16 {
+    fn from(layers: CollisionLayers) -> Self {
+        layers.memberships
+    }
+}
+
+/// Which layers interact with each other. By default every layer interacts with every other.
+#[derive(Resource, Debug, Clone)]
+pub struct CollisionLayerMatrix {
+    masks: [u16; 16],
+}
+
+impl Default for CollisionLayerMatrix {
+    fn default() -> Self {
+        Self {
+            masks: [0xFFFF; 16],
+        }
+    }
+}
+
+impl CollisionLayerMatrix {
+    /// Creates a matrix where no layers interact.
+    pub fn empty() -> Self {
+        Self { masks: [0; 16] }
+    }
+
+    /// Sets whether two layers interact. Interactions are always symmetric.
+    pub fn set_interaction<L: PhysicsLayer>(&mut self, a: L, b: L, interacts: bool) -> &mut Self {
+        if interacts {
+            self.masks[a.index() as usize] |= b.bit();
+            self.masks[b.index() as usize] |= a.bit();
+        } else {
+            self.masks[a.index() as usize] &= !b.bit();
+            self.masks[b.index() as usize] &= !a.bit();
+        }
+        self
+    }
+
+    pub fn interacts<L: PhysicsLayer>(&self, a: L, b: L) -> bool {
+        self.masks[a.index() as usize] & b.bit() != 0
+    }
+
+    /// The union of the layers that any of the given member layers interact with.
+    pub fn mask(&self, memberships: u16) -> u16 {
+        (0..16)
+            .filter(|index| memberships & (1 << index) != 0)
+            .fold(0, |mask, index| mask | self.masks[index])
+    }
+}
@@ -0,0 +1,67 @@
+use std::fmt::{Debug, Formatter};
+
+use bevy::prelude::{Commands, Component, Deref, Entity};
+use bevy::utils::HashSet;
+
+use crate::dynamics::b2Contact;
+
+/// Opt-in set of the entities currently touching this entity. On a body it holds the other
+/// bodies, on a fixture the other fixtures. Kept up to date by the plugin after every frame's
+/// physics steps.
+#[derive(Component, Debug, Default, Deref)]
+pub struct CollidingEntities(HashSet<Entity>);
+
+impl CollidingEntities {
+    pub(crate) fn set(&mut self, entities: HashSet<Entity>) {
+        self.0 = entities;
+    }
+}
+
+pub type ContactCallback = Box<dyn Fn(&mut Commands, Entity, &b2Contact) + Send + Sync>;
+
+/// Callbacks run for contacts that begin or end on this body or fixture. Each callback receives
+/// the entity the component is attached to along with the contact.
+#[derive(Component, Default)]
+pub struct ContactCallbacks {
+    on_begin: Vec<ContactCallback>,
+    on_end: Vec<ContactCallback>,
+}
+
+impl ContactCallbacks {
+    pub fn on_begin<F: Fn(&mut Commands, Entity, &b2Contact) + Send + Sync + 'static>(
+        mut self,
+        callback: F,
+    ) -> Self {
+        self.on_begin.push(Box::new(callback));
+        self
+    }
+
+    pub fn on_end<F: Fn(&mut Commands, Entity, &b2Contact) + Send + Sync + 'static>(
+        mut self,
+        callback: F,
+    ) -> Self {
+        self.on_end.push(Box::new(callback));
+        self
+    }
+
+    pub(crate) fn run_begin(&self, commands: &mut Commands, entity: Entity, contact: &b2Contact) {
+        self.on_begin
+            .iter()
+            .for_each(|callback| callback(commands, entity, contact));
+    }
+
+    pub(crate) fn run_end(&self, commands: &mut Commands, entity: Entity, contact: &b2Contact) {
+        self.on_end
+            .iter()
+            .for_each(|callback| callback(commands, entity, contact));
+    }
+}
+
+impl Debug for ContactCallbacks {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContactCallbacks")
+            .field("on_begin", &self.on_begin.len())
+            .field("on_end", &self.on_end.len())
+            .finish()
+    }
+}
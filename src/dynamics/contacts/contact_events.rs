@@ -1,6 +1,8 @@
-use bevy::prelude::Event;
+use std::any::TypeId;
 
-use crate::dynamics::b2Contact;
+use bevy::prelude::{Component, Entity, Event};
+
+use crate::dynamics::{b2Contact, b2ParticleBodyContact, b2ParticleContact};
 
 #[allow(non_camel_case_types)]
 #[derive(Event, Debug, Copy, Clone)]
@@ -9,3 +11,137 @@ pub struct b2BeginContactEvent(pub b2Contact);
 #[allow(non_camel_case_types)]
 #[derive(Event, Debug, Copy, Clone)]
 pub struct b2EndContactEvent(pub b2Contact);
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum b2ContactEventKind {
+    Begin,
+    End,
+}
+
+/// Every begin and end of a contact in the order Box2D reported them, including contacts that
+/// began and ended several times during the frame's physics steps. Unlike
+/// [`b2BeginContactEvent`] and [`b2EndContactEvent`], nothing is deduplicated.
+#[allow(non_camel_case_types)]
+#[derive(Event, Debug, Copy, Clone)]
+pub struct b2ContactStepEvent {
+    pub kind: b2ContactEventKind,
+    /// The index of the physics step within the frame during which the event happened. Contacts
+    /// ended by destroying bodies or fixtures get the index of the step that follows.
+    pub step: u32,
+    pub contact: b2Contact,
+}
+
+/// Sent at most once per contact per frame, carrying the largest impulse the contact received
+/// during the frame's steps.
+#[allow(non_camel_case_types)]
+#[derive(Event, Debug, Copy, Clone)]
+pub struct b2ContactImpulseEvent(pub b2Contact);
+
+/// Suppresses [`b2ContactImpulseEvent`]s for contacts of this body whose largest normal impulse
+/// is below the threshold. When both bodies have a threshold, the higher one applies.
+#[derive(Component, Debug, Copy, Clone, Default)]
+pub struct ContactImpulseThreshold(pub f32);
+
+/// Sent when a fixture starts overlapping a sensor fixture that accepts it.
+#[derive(Event, Debug, Copy, Clone)]
+pub struct SensorEnterEvent {
+    pub sensor: Entity,
+    pub sensor_body: Entity,
+    pub visitor: Entity,
+    pub visitor_body: Entity,
+}
+
+/// Sent when a fixture that caused a [`SensorEnterEvent`] stops overlapping the sensor.
+#[derive(Event, Debug, Copy, Clone)]
+pub struct SensorExitEvent {
+    pub sensor: Entity,
+    pub sensor_body: Entity,
+    pub visitor: Entity,
+    pub visitor_body: Entity,
+}
+
+/// Restricts the [`SensorEnterEvent`]s and [`SensorExitEvent`]s of a sensor fixture to the
+/// visitors that match. Without this component a sensor accepts every visitor.
+#[derive(Component, Debug, Clone)]
+pub struct SensorFilter {
+    /// The collision categories a visitor fixture must belong to, matched against
+    /// [`b2Filter::category`](crate::dynamics::b2Filter::category).
+    pub categories: u16,
+    required_components: Vec<TypeId>,
+}
+
+impl Default for SensorFilter {
+    fn default() -> Self {
+        Self {
+            categories: 0xFFFF,
+            required_components: Vec::new(),
+        }
+    }
+}
+
+impl SensorFilter {
+    pub fn with_categories(categories: u16) -> Self {
+        Self {
+            categories,
+            ..Self::default()
+        }
+    }
+
+    /// Only accepts visitors whose fixture or body has a `T` component.
+    pub fn require_component<T: Component>(mut self) -> Self {
+        self.required_components.push(TypeId::of::<T>());
+        self
+    }
+
+    pub(crate) fn accepts(&self, category: u16, has_component: impl Fn(TypeId) -> bool) -> bool {
+        self.categories & category != 0
+            && self
+                .required_components
+                .iter()
+                .all(|type_id| has_component(*type_id))
+    }
+}
+
+/// Sent when a particle with the
+/// [`FixtureContactListenerParticle`](crate::particles::b2ParticleFlags::FixtureContactListenerParticle)
+/// flag starts touching a fixture.
+#[allow(non_camel_case_types)]
+#[derive(Event, Debug, Copy, Clone)]
+pub struct b2ParticleBodyBeginEvent {
+    pub particle_system: Entity,
+    pub contact: b2ParticleBodyContact,
+}
+
+/// Sent when a particle with the
+/// [`FixtureContactListenerParticle`](crate::particles::b2ParticleFlags::FixtureContactListenerParticle)
+/// flag stops touching a fixture.
+#[allow(non_camel_case_types)]
+#[derive(Event, Debug, Copy, Clone)]
+pub struct b2ParticleBodyEndEvent {
+    pub particle_system: Entity,
+    pub particle_index: i32,
+    pub fixture: Entity,
+    pub body: Entity,
+}
+
+/// Sent when a particle with the
+/// [`ParticleContactListenerParticle`](crate::particles::b2ParticleFlags::ParticleContactListenerParticle)
+/// flag starts touching another particle of the same system.
+#[allow(non_camel_case_types)]
+#[derive(Event, Debug, Copy, Clone)]
+pub struct b2ParticleParticleBeginEvent {
+    pub particle_system: Entity,
+    pub contact: b2ParticleContact,
+}
+
+/// Sent when a particle with the
+/// [`ParticleContactListenerParticle`](crate::particles::b2ParticleFlags::ParticleContactListenerParticle)
+/// flag stops touching another particle of the same system.
+#[allow(non_camel_case_types)]
+#[derive(Event, Debug, Copy, Clone)]
+pub struct b2ParticleParticleEndEvent {
+    pub particle_system: Entity,
+    pub particle_index_a: i32,
+    pub particle_index_b: i32,
+}
@@ -0,0 +1,146 @@
+use std::pin::Pin;
+
+use bevy::prelude::Entity;
+
+use libliquidfun_sys::box2d::ffi::b2Fixture as ffi_b2Fixture;
+use libliquidfun_sys::box2d::ffi::{b2ContactFilterImpl, b2ParticleSystem};
+
+use crate::dynamics::b2ParticleSystemEntities;
+
+/// Decides whether two fixtures should collide, on top of the category, mask and group rules of
+/// [`b2Filter`](crate::dynamics::b2Filter). The callbacks are only asked about pairs that the
+/// fixtures' filters already allow.
+#[allow(non_camel_case_types)]
+pub trait b2ContactFilter {
+    fn should_collide(
+        &mut self,
+        fixture_a: Entity,
+        body_a: Entity,
+        fixture_b: Entity,
+        body_b: Entity,
+    ) -> bool;
+
+    /// Only called for particles with the
+    /// [`FixtureContactFilterParticle`](crate::particles::b2ParticleFlags::FixtureContactFilterParticle)
+    /// flag.
+    fn should_collide_fixture_particle(
+        &mut self,
+        _fixture: Entity,
+        _body: Entity,
+        _particle_system: Entity,
+        _particle_index: i32,
+    ) -> bool {
+        true
+    }
+
+    /// Only called for particles with the
+    /// [`ParticleContactFilterParticle`](crate::particles::b2ParticleFlags::ParticleContactFilterParticle)
+    /// flag.
+    fn should_collide_particle_particle(
+        &mut self,
+        _particle_system: Entity,
+        _particle_index_a: i32,
+        _particle_index_b: i32,
+    ) -> bool {
+        true
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub(crate) struct b2ShouldCollide {
+    filter: Box<dyn b2ContactFilter>,
+    particle_systems: b2ParticleSystemEntities,
+}
+
+impl b2ShouldCollide {
+    pub(crate) fn new(
+        filter: Box<dyn b2ContactFilter>,
+        particle_systems: b2ParticleSystemEntities,
+    ) -> Self {
+        Self {
+            filter,
+            particle_systems,
+        }
+    }
+}
+
+/// Box2D's default rules for [`b2Filter`](crate::dynamics::b2Filter), which get replaced as soon
+/// as a custom contact filter is installed on the world.
+fn filters_allow_collision(
+    mut fixture_a: Pin<&mut ffi_b2Fixture>,
+    mut fixture_b: Pin<&mut ffi_b2Fixture>,
+) -> bool {
+    let filter_a = fixture_a.as_mut().GetFilterData();
+    let filter_b = fixture_b.as_mut().GetFilterData();
+    let group_a = i16::from(filter_a.groupIndex);
+    let group_b = i16::from(filter_b.groupIndex);
+
+    if group_a == group_b && group_a != 0 {
+        return group_a > 0;
+    }
+
+    u16::from(filter_a.maskBits) & u16::from(filter_b.categoryBits) != 0
+        && u16::from(filter_a.categoryBits) & u16::from(filter_b.maskBits) != 0
+}
+
+fn fixture_and_body_entities(mut fixture: Pin<&mut ffi_b2Fixture>) -> (Entity, Entity) {
+    unsafe {
+        let fixture_entity =
+            Entity::from_bits(fixture.as_mut().GetUserData().get_unchecked_mut().pointer as u64);
+        let mut body = Pin::new_unchecked(fixture.as_mut().GetBody().as_mut().unwrap());
+        let body_entity =
+            Entity::from_bits(body.as_mut().GetUserData().get_unchecked_mut().pointer as u64);
+        (fixture_entity, body_entity)
+    }
+}
+
+impl b2ContactFilterImpl for b2ShouldCollide {
+    fn should_collide(
+        &mut self,
+        fixture_a: &mut ffi_b2Fixture,
+        fixture_b: &mut ffi_b2Fixture,
+    ) -> bool {
+        unsafe {
+            let mut fixture_a = Pin::new_unchecked(fixture_a);
+            let mut fixture_b = Pin::new_unchecked(fixture_b);
+            if !filters_allow_collision(fixture_a.as_mut(), fixture_b.as_mut()) {
+                return false;
+            }
+
+            let (fixture_a, body_a) = fixture_and_body_entities(fixture_a);
+            let (fixture_b, body_b) = fixture_and_body_entities(fixture_b);
+            self.filter
+                .should_collide(fixture_a, body_a, fixture_b, body_b)
+        }
+    }
+
+    fn should_collide_fixture_particle(
+        &mut self,
+        fixture: &mut ffi_b2Fixture,
+        particle_system: &mut b2ParticleSystem,
+        particle_index: i32,
+    ) -> bool {
+        let Some(particle_system) = self.particle_systems.get(particle_system) else {
+            return true;
+        };
+        let (fixture, body) = fixture_and_body_entities(unsafe { Pin::new_unchecked(fixture) });
+        self.filter
+            .should_collide_fixture_particle(fixture, body, particle_system, particle_index)
+    }
+
+    fn should_collide_particle_particle(
+        &mut self,
+        particle_system: &mut b2ParticleSystem,
+        particle_index_a: i32,
+        particle_index_b: i32,
+    ) -> bool {
+        let Some(particle_system) = self.particle_systems.get(particle_system) else {
+            return true;
+        };
+        self.filter.should_collide_particle_particle(
+            particle_system,
+            particle_index_a,
+            particle_index_b,
+        )
+    }
+}
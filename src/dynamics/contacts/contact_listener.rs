@@ -1,13 +1,20 @@
 use std::collections::{HashMap, HashSet};
+use std::pin::Pin;
 
 use bevy::prelude::Entity;
 
 use libliquidfun_sys::box2d::ffi::{
-    b2Contact as ffi_b2Contact, b2ContactImpulse, b2ContactListenerImpl, b2Fixture, b2Manifold,
-    b2ParticleBodyContact as ffi_b2ParticleBodyContact, b2ParticleContact, b2ParticleSystem,
+    b2Contact as ffi_b2Contact, b2ContactImpulse as ffi_b2ContactImpulse, b2ContactListenerImpl,
+    b2Fixture, b2Manifold, b2ParticleBodyContact as ffi_b2ParticleBodyContact,
+    b2ParticleContact as ffi_b2ParticleContact, b2ParticleSystem,
 };
 
-use crate::dynamics::b2Contact;
+use crate::dynamics::{
+    b2Contact, b2ContactEventKind, b2ContactImpulse, b2ContactStepEvent, b2ParticleBodyBeginEvent,
+    b2ParticleBodyContact, b2ParticleBodyEndEvent, b2ParticleContact, b2ParticleParticleBeginEvent,
+    b2ParticleParticleEndEvent, b2ParticleSystemEntities, b2PreSolveHook, b2PreSolveHooks,
+    OneWayPlatform,
+};
 
 #[allow(non_camel_case_types)]
 #[derive(Debug)]
@@ -15,17 +22,69 @@ pub struct b2ContactListener {
     fixture_contacts: HashMap<(Entity, Entity), b2Contact>,
     begun_fixture_contacts: HashSet<(Entity, Entity)>,
     ended_fixture_contacts: HashMap<(Entity, Entity), b2Contact>,
+    strongest_contact_impulses: HashMap<(Entity, Entity), b2Contact>,
+    contact_impulse_thresholds: HashMap<Entity, f32>,
+    contact_step_events: Vec<b2ContactStepEvent>,
+    step: u32,
+
+    particle_systems: b2ParticleSystemEntities,
+    particle_body_begin_events: Vec<b2ParticleBodyBeginEvent>,
+    particle_body_end_events: Vec<b2ParticleBodyEndEvent>,
+    particle_particle_begin_events: Vec<b2ParticleParticleBeginEvent>,
+    particle_particle_end_events: Vec<b2ParticleParticleEndEvent>,
+
+    one_way_platforms: HashMap<Entity, OneWayPlatform>,
+    one_way_platform_contacts: HashMap<(Entity, Entity), bool>,
+    pre_solve_hooks: b2PreSolveHooks,
 }
 
 impl b2ContactListener {
     pub fn new() -> Self {
+        Self::with_particle_systems(b2ParticleSystemEntities::default())
+    }
+
+    /// Creates a listener that resolves particle systems through the given map, shared with the
+    /// world that registers them.
+    pub(crate) fn with_particle_systems(particle_systems: b2ParticleSystemEntities) -> Self {
         Self {
             fixture_contacts: Default::default(),
             begun_fixture_contacts: Default::default(),
             ended_fixture_contacts: Default::default(),
+            strongest_contact_impulses: Default::default(),
+            contact_impulse_thresholds: Default::default(),
+            contact_step_events: Default::default(),
+            step: 0,
+            particle_systems,
+            particle_body_begin_events: Default::default(),
+            particle_body_end_events: Default::default(),
+            particle_particle_begin_events: Default::default(),
+            particle_particle_end_events: Default::default(),
+            one_way_platforms: Default::default(),
+            one_way_platform_contacts: Default::default(),
+            pre_solve_hooks: Default::default(),
         }
     }
 
+    pub(crate) fn set_one_way_platform(&mut self, fixture: Entity, platform: OneWayPlatform) {
+        self.one_way_platforms.insert(fixture, platform);
+    }
+
+    pub(crate) fn remove_one_way_platform(&mut self, fixture: Entity) {
+        self.one_way_platforms.remove(&fixture);
+    }
+
+    pub(crate) fn set_contact_impulse_threshold(&mut self, body: Entity, threshold: f32) {
+        self.contact_impulse_thresholds.insert(body, threshold);
+    }
+
+    pub(crate) fn remove_contact_impulse_threshold(&mut self, body: Entity) {
+        self.contact_impulse_thresholds.remove(&body);
+    }
+
+    pub(crate) fn add_pre_solve_hook(&mut self, hook: Box<dyn b2PreSolveHook>) {
+        self.pre_solve_hooks.add(hook);
+    }
+
     pub fn fixture_contacts(&self) -> &HashMap<(Entity, Entity), b2Contact> {
         &self.fixture_contacts
     }
@@ -38,9 +97,97 @@ impl b2ContactListener {
         &self.ended_fixture_contacts
     }
 
+    /// Replaces the stored state of a touching contact with its current state, keeping the last
+    /// impulse it received.
+    pub(crate) fn refresh_fixture_contact(&mut self, mut contact: b2Contact) {
+        let key = contact.get_contact_key();
+        if let Some(stored_contact) = self.fixture_contacts.get_mut(&key) {
+            contact.impulse = stored_contact.impulse;
+            *stored_contact = contact;
+        }
+    }
+
+    /// The contacts solved since the last clear, each with the strongest impulse it received.
+    /// Contacts that stayed below their bodies' [`ContactImpulseThreshold`] are left out.
+    ///
+    /// [`ContactImpulseThreshold`]: crate::dynamics::ContactImpulseThreshold
+    pub fn strongest_contact_impulses(&self) -> &HashMap<(Entity, Entity), b2Contact> {
+        &self.strongest_contact_impulses
+    }
+
+    /// All contact begins and ends since the last clear, in the order they happened.
+    pub fn contact_step_events(&self) -> &Vec<b2ContactStepEvent> {
+        &self.contact_step_events
+    }
+
+    pub fn particle_body_begin_events(&self) -> &Vec<b2ParticleBodyBeginEvent> {
+        &self.particle_body_begin_events
+    }
+
+    pub fn particle_body_end_events(&self) -> &Vec<b2ParticleBodyEndEvent> {
+        &self.particle_body_end_events
+    }
+
+    pub fn particle_particle_begin_events(&self) -> &Vec<b2ParticleParticleBeginEvent> {
+        &self.particle_particle_begin_events
+    }
+
+    pub fn particle_particle_end_events(&self) -> &Vec<b2ParticleParticleEndEvent> {
+        &self.particle_particle_end_events
+    }
+
+    pub fn clear_particle_contact_changes(&mut self) {
+        self.particle_body_begin_events.clear();
+        self.particle_body_end_events.clear();
+        self.particle_particle_begin_events.clear();
+        self.particle_particle_end_events.clear();
+    }
+
+    pub(crate) fn advance_step(&mut self) {
+        self.step += 1;
+    }
+
     pub fn clear_contact_changes(&mut self) {
         self.begun_fixture_contacts.clear();
         self.ended_fixture_contacts.clear();
+        self.strongest_contact_impulses.clear();
+        self.contact_step_events.clear();
+        self.step = 0;
+    }
+
+    fn apply_one_way_platforms(&mut self, contact: &mut ffi_b2Contact) {
+        let (fixture_a, fixture_b) = b2Contact::fixture_entities_from_ffi(contact);
+        let key = (
+            Entity::min(fixture_a, fixture_b),
+            Entity::max(fixture_a, fixture_b),
+        );
+
+        let mut contact = unsafe { Pin::new_unchecked(contact) };
+        // Box2D re-enables every contact before pre-solve, so the decision made when the contact
+        // began has to be re-applied on every step until the contact ends.
+        let enabled = match self.one_way_platform_contacts.get(&key) {
+            Some(enabled) => *enabled,
+            None => {
+                let platform = self
+                    .one_way_platforms
+                    .get(&fixture_a)
+                    .map(|platform| (platform, true))
+                    .or(self
+                        .one_way_platforms
+                        .get(&fixture_b)
+                        .map(|platform| (platform, false)));
+                let Some((platform, platform_is_fixture_a)) = platform else {
+                    return;
+                };
+                let enabled = platform.allows_contact(contact.as_mut(), platform_is_fixture_a);
+                self.one_way_platform_contacts.insert(key, enabled);
+                enabled
+            }
+        };
+
+        if !enabled {
+            contact.as_mut().SetEnabled(false);
+        }
     }
 }
 
@@ -50,39 +197,142 @@ impl b2ContactListenerImpl for b2ContactListener {
         let key = contact.get_contact_key();
         self.fixture_contacts.insert(key, contact);
         self.begun_fixture_contacts.insert(key);
+        self.contact_step_events.push(b2ContactStepEvent {
+            kind: b2ContactEventKind::Begin,
+            step: self.step,
+            contact,
+        });
     }
     fn end_contact(&mut self, contact: &mut ffi_b2Contact) {
         let contact = b2Contact::from_ffi_contact(contact);
         let key = contact.get_contact_key();
         self.fixture_contacts.remove(&key);
+        self.one_way_platform_contacts.remove(&key);
         self.ended_fixture_contacts.insert(key, contact);
+        self.contact_step_events.push(b2ContactStepEvent {
+            kind: b2ContactEventKind::End,
+            step: self.step,
+            contact,
+        });
     }
     fn begin_particle_body_contact(
         &mut self,
-        _particle_system: &mut b2ParticleSystem,
-        _contact: &mut ffi_b2ParticleBodyContact,
+        particle_system: &mut b2ParticleSystem,
+        contact: &mut ffi_b2ParticleBodyContact,
     ) {
+        let Some(particle_system) = self.particle_systems.get(particle_system) else {
+            return;
+        };
+        self.particle_body_begin_events
+            .push(b2ParticleBodyBeginEvent {
+                particle_system,
+                contact: b2ParticleBodyContact::from_ffi_contact(contact),
+            });
     }
     fn end_particle_body_contact(
         &mut self,
-        _fixture: &mut b2Fixture,
-        _particle_system: &mut b2ParticleSystem,
-        _particle_index: i32,
+        fixture: &mut b2Fixture,
+        particle_system: &mut b2ParticleSystem,
+        particle_index: i32,
     ) {
+        let Some(particle_system) = self.particle_systems.get(particle_system) else {
+            return;
+        };
+        unsafe {
+            let mut fixture = Pin::new_unchecked(fixture);
+            let mut body = Pin::new_unchecked(fixture.as_mut().GetBody().as_mut().unwrap());
+            let fixture_entity = Entity::from_bits(
+                fixture.as_mut().GetUserData().get_unchecked_mut().pointer as u64,
+            );
+            let body_entity =
+                Entity::from_bits(body.as_mut().GetUserData().get_unchecked_mut().pointer as u64);
+            self.particle_body_end_events.push(b2ParticleBodyEndEvent {
+                particle_system,
+                particle_index,
+                fixture: fixture_entity,
+                body: body_entity,
+            });
+        }
     }
     fn begin_particle_particle_contact(
         &mut self,
-        _particle_system: &mut b2ParticleSystem,
-        _contact: &mut b2ParticleContact,
+        particle_system: &mut b2ParticleSystem,
+        contact: &mut ffi_b2ParticleContact,
     ) {
+        let Some(particle_system) = self.particle_systems.get(particle_system) else {
+            return;
+        };
+        self.particle_particle_begin_events
+            .push(b2ParticleParticleBeginEvent {
+                particle_system,
+                contact: b2ParticleContact::from_ffi_contact(contact),
+            });
     }
     fn end_particle_particle_contact(
         &mut self,
-        _particle_system: &mut b2ParticleSystem,
-        _index_a: i32,
-        _index_b: i32,
+        particle_system: &mut b2ParticleSystem,
+        index_a: i32,
+        index_b: i32,
     ) {
+        let Some(particle_system) = self.particle_systems.get(particle_system) else {
+            return;
+        };
+        self.particle_particle_end_events
+            .push(b2ParticleParticleEndEvent {
+                particle_system,
+                particle_index_a: index_a,
+                particle_index_b: index_b,
+            });
+    }
+    fn pre_solve(&mut self, contact: &mut ffi_b2Contact, _old_manifold: &b2Manifold) {
+        if !self.one_way_platforms.is_empty() {
+            self.apply_one_way_platforms(contact);
+        }
+        if !self.pre_solve_hooks.is_empty() {
+            self.pre_solve_hooks.run(contact);
+        }
+    }
+    fn post_solve(&mut self, contact: &mut ffi_b2Contact, impulse: &ffi_b2ContactImpulse) {
+        let (fixture_a, fixture_b) = b2Contact::fixture_entities_from_ffi(contact);
+        let key = (
+            Entity::min(fixture_a, fixture_b),
+            Entity::max(fixture_a, fixture_b),
+        );
+
+        let Some(persisting_contact) = self.fixture_contacts.get_mut(&key) else {
+            return;
+        };
+        persisting_contact.impulse = b2ContactImpulse::from_ffi(impulse);
+
+        // checked on the raw impulses, so that only contacts reaching the threshold are read back
+        let max_normal_impulse = impulse
+            .normalImpulses
+            .iter()
+            .take(i32::from(impulse.count) as usize)
+            .copied()
+            .fold(0., f32::max);
+        let threshold = |body| {
+            self.contact_impulse_thresholds
+                .get(&body)
+                .copied()
+                .unwrap_or(0.)
+        };
+        let threshold = f32::max(
+            threshold(persisting_contact.body_a),
+            threshold(persisting_contact.body_b),
+        );
+        if max_normal_impulse < threshold {
+            return;
+        }
+
+        if let Some(strongest) = self.strongest_contact_impulses.get(&key) {
+            if strongest.impulse.max_normal_impulse() >= max_normal_impulse {
+                return;
+            }
+        }
+
+        let mut contact = b2Contact::from_ffi_contact(contact);
+        contact.impulse = b2ContactImpulse::from_ffi(impulse);
+        self.strongest_contact_impulses.insert(key, contact);
     }
-    fn pre_solve(&mut self, _contact: &mut ffi_b2Contact, _old_manifold: &b2Manifold) {}
-    fn post_solve(&mut self, _contact: &mut ffi_b2Contact, _impulse: &b2ContactImpulse) {}
 }
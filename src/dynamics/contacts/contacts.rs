@@ -1,4 +1,3 @@
-use std::collections::HashSet;
 use std::pin::Pin;
 
 use autocxx::WithinBox;
@@ -6,10 +5,13 @@ use bevy::math::Vec2;
 use bevy::prelude::{Component, Entity};
 
 use libliquidfun_sys::box2d::ffi::{
-    b2Contact as ffi_b2Contact, b2ParticleBodyContact as ffi_b2ParticleBodyContact, b2WorldManifold,
+    b2Contact as ffi_b2Contact, b2ContactImpulse as ffi_b2ContactImpulse,
+    b2ParticleBodyContact as ffi_b2ParticleBodyContact, b2ParticleContact as ffi_b2ParticleContact,
+    b2WorldManifold,
 };
 
 use crate::internal::to_Vec2;
+use crate::particles::b2ParticleFlags;
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Copy, Clone)]
@@ -18,8 +20,22 @@ pub struct b2Contact {
     pub fixture_b: Entity,
     pub body_a: Entity,
     pub body_b: Entity,
+    /// The world-space manifold points. Only the first `point_count` entries are valid, the rest
+    /// are zero.
     pub points: [Vec2; 2],
+    /// The number of valid manifold points, between 0 and 2.
+    pub point_count: usize,
+    /// The separation of each manifold point. Negative values mean penetration.
+    pub separations: [f32; 2],
     pub normal: Vec2,
+    pub friction: f32,
+    pub restitution: f32,
+    pub tangent_speed: f32,
+    /// False if the contact was disabled for the last step, e.g. by a pre-solve hook.
+    pub enabled: bool,
+    pub touching: bool,
+    /// The impulse applied by the most recent solver step. Zero until the contact is solved.
+    pub impulse: b2ContactImpulse,
 }
 
 impl b2Contact {
@@ -44,11 +60,17 @@ impl b2Contact {
             let body_b_entity =
                 Entity::from_bits(body_b.as_mut().GetUserData().get_unchecked_mut().pointer as u64);
 
+            let point_count =
+                i32::from(contact.as_mut().GetManifold().as_ref().unwrap().pointCount) as usize;
             let mut manifold = b2WorldManifold::new().within_box();
             let manifold_ptr = manifold.as_mut().get_unchecked_mut() as *mut b2WorldManifold;
             contact.as_ref().GetWorldManifold(manifold_ptr);
-            let points = &manifold.points;
-            let points = [to_Vec2(&points[0]), to_Vec2(&points[1])];
+            let mut points = [Vec2::ZERO; 2];
+            let mut separations = [0.; 2];
+            for i in 0..point_count {
+                points[i] = to_Vec2(&manifold.points[i]);
+                separations[i] = manifold.separations[i];
+            }
             let normal = to_Vec2(&manifold.normal);
 
             b2Contact {
@@ -57,11 +79,47 @@ impl b2Contact {
                 body_a: body_a_entity,
                 body_b: body_b_entity,
                 points,
+                point_count,
+                separations,
                 normal,
+                friction: contact.as_ref().GetFriction(),
+                restitution: contact.as_ref().GetRestitution(),
+                tangent_speed: contact.as_ref().GetTangentSpeed(),
+                enabled: contact.as_ref().IsEnabled(),
+                touching: contact.as_ref().IsTouching(),
+                impulse: b2ContactImpulse::default(),
             }
         }
     }
 
+    /// The valid manifold points.
+    pub fn manifold_points(&self) -> &[Vec2] {
+        &self.points[..self.point_count]
+    }
+
+    /// The separations of the valid manifold points.
+    pub fn manifold_separations(&self) -> &[f32] {
+        &self.separations[..self.point_count]
+    }
+
+    pub(crate) fn fixture_entities_from_ffi(contact: &mut ffi_b2Contact) -> (Entity, Entity) {
+        unsafe {
+            let mut contact = Pin::new_unchecked(contact);
+            let mut fixture_a =
+                Pin::new_unchecked(contact.as_mut().GetFixtureA().as_mut().unwrap());
+            let mut fixture_b =
+                Pin::new_unchecked(contact.as_mut().GetFixtureB().as_mut().unwrap());
+
+            let fixture_a_entity = Entity::from_bits(
+                fixture_a.as_mut().GetUserData().get_unchecked_mut().pointer as u64,
+            );
+            let fixture_b_entity = Entity::from_bits(
+                fixture_b.as_mut().GetUserData().get_unchecked_mut().pointer as u64,
+            );
+            (fixture_a_entity, fixture_b_entity)
+        }
+    }
+
     pub(crate) fn get_contact_key(&self) -> (Entity, Entity) {
         (
             Entity::min(self.fixture_a, self.fixture_b),
@@ -70,6 +128,45 @@ impl b2Contact {
     }
 }
 
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct b2ContactImpulse {
+    /// The impulses along the contact normal, one per manifold point, in N*s.
+    pub normal_impulses: [f32; 2],
+
+    /// The friction impulses along the contact tangent, one per manifold point, in N*s.
+    pub tangent_impulses: [f32; 2],
+
+    /// The number of manifold points with valid impulses.
+    pub count: usize,
+}
+
+impl b2ContactImpulse {
+    pub(crate) fn from_ffi(impulse: &ffi_b2ContactImpulse) -> Self {
+        b2ContactImpulse {
+            normal_impulses: impulse.normalImpulses,
+            tangent_impulses: impulse.tangentImpulses,
+            count: i32::from(impulse.count) as usize,
+        }
+    }
+
+    pub fn max_normal_impulse(&self) -> f32 {
+        self.normal_impulses
+            .iter()
+            .take(self.count)
+            .copied()
+            .fold(0., f32::max)
+    }
+
+    pub fn max_tangent_impulse(&self) -> f32 {
+        self.tangent_impulses
+            .iter()
+            .take(self.count)
+            .map(|impulse| impulse.abs())
+            .fold(0., f32::max)
+    }
+}
+
 #[allow(non_camel_case_types)]
 #[derive(Debug, Copy, Clone)]
 pub struct b2ParticleBodyContact {
@@ -108,26 +205,65 @@ impl b2ParticleBodyContact {
     }
 }
 
+/// A contact between two particles of the same particle system.
 #[allow(non_camel_case_types)]
-#[derive(Component, Debug)]
-pub struct b2ParticleContacts {
-    contacts: HashSet<i32>,
+#[derive(Debug, Copy, Clone)]
+pub struct b2ParticleContact {
+    pub index_a: i32,
+    pub index_b: i32,
+    pub weight: f32,
+    /// Points from particle A to particle B.
+    pub normal: Vec2,
+    /// The union of the flags of both particles.
+    pub flags: b2ParticleFlags,
 }
 
-impl Default for b2ParticleContacts {
-    fn default() -> Self {
-        Self {
-            contacts: Default::default(),
+impl b2ParticleContact {
+    pub(crate) fn from_ffi_contact(contact: &ffi_b2ParticleContact) -> Self {
+        b2ParticleContact {
+            index_a: i32::from(contact.GetIndexA()),
+            index_b: i32::from(contact.GetIndexB()),
+            weight: contact.GetWeight(),
+            normal: to_Vec2(contact.GetNormal()),
+            flags: b2ParticleFlags::from_bits_truncate(u32::from(contact.GetFlags())),
         }
     }
 }
 
+/// A particle touching a body, as seen from the body's [`b2ParticleContacts`].
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone)]
+pub struct b2BodyParticleContact {
+    pub particle_system: Entity,
+    pub particle_index: i32,
+    pub fixture: Entity,
+    pub weight: f32,
+    /// Points from the particle towards the body.
+    pub normal: Vec2,
+    pub mass: f32,
+}
+
+/// Opt-in list of the particles currently touching this body, across all particle systems.
+#[allow(non_camel_case_types)]
+#[derive(Component, Debug, Default)]
+pub struct b2ParticleContacts {
+    contacts: Vec<b2BodyParticleContact>,
+}
+
 impl b2ParticleContacts {
-    pub fn contacts(&self) -> &HashSet<i32> {
+    pub fn contacts(&self) -> &Vec<b2BodyParticleContact> {
         &self.contacts
     }
 
-    pub(crate) fn contacts_mut(&mut self) -> &mut HashSet<i32> {
+    /// The indices of the touching particles that belong to the given particle system.
+    pub fn particle_indices(&self, particle_system: Entity) -> impl Iterator<Item = i32> + '_ {
+        self.contacts
+            .iter()
+            .filter(move |contact| contact.particle_system == particle_system)
+            .map(|contact| contact.particle_index)
+    }
+
+    pub(crate) fn contacts_mut(&mut self) -> &mut Vec<b2BodyParticleContact> {
         &mut self.contacts
     }
 }
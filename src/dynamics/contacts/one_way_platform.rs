@@ -0,0 +1,82 @@
+use std::pin::Pin;
+
+use autocxx::WithinBox;
+use bevy::math::Vec2;
+use bevy::prelude::Component;
+
+use libliquidfun_sys::box2d::ffi::{b2Contact as ffi_b2Contact, b2WorldManifold};
+
+use crate::internal::{to_Vec2, to_b2Vec2};
+
+/// Relative velocity along the platform normal (in m/s) below which a body is considered to be
+/// landing on the platform rather than passing through it.
+const LANDING_VELOCITY_TOLERANCE: f32 = 0.01;
+
+/// Makes a fixture collide only with bodies approaching it from the side its normal points to.
+/// Bodies coming from any other direction pass through, and keep passing through until the
+/// contact ends.
+#[derive(Component, Debug, Copy, Clone)]
+pub struct OneWayPlatform {
+    /// The solid side of the platform, in the local space of the platform's body.
+    pub normal: Vec2,
+}
+
+impl Default for OneWayPlatform {
+    fn default() -> Self {
+        Self { normal: Vec2::Y }
+    }
+}
+
+impl OneWayPlatform {
+    pub fn new(normal: Vec2) -> Self {
+        Self { normal }
+    }
+
+    /// Decides whether a freshly begun contact with this platform should be solved.
+    pub(crate) fn allows_contact(
+        &self,
+        mut contact: Pin<&mut ffi_b2Contact>,
+        platform_is_fixture_a: bool,
+    ) -> bool {
+        unsafe {
+            let mut fixture_a =
+                Pin::new_unchecked(contact.as_mut().GetFixtureA().as_mut().unwrap());
+            let mut fixture_b =
+                Pin::new_unchecked(contact.as_mut().GetFixtureB().as_mut().unwrap());
+            let body_a = Pin::new_unchecked(fixture_a.as_mut().GetBody().as_mut().unwrap());
+            let body_b = Pin::new_unchecked(fixture_b.as_mut().GetBody().as_mut().unwrap());
+            let (platform_body, other_body) = if platform_is_fixture_a {
+                (body_a, body_b)
+            } else {
+                (body_b, body_a)
+            };
+
+            let normal = to_Vec2(
+                &platform_body
+                    .as_ref()
+                    .GetWorldVector(&to_b2Vec2(&self.normal)),
+            );
+
+            let point_count =
+                i32::from(contact.as_mut().GetManifold().as_ref().unwrap().pointCount);
+            let mut manifold = b2WorldManifold::new().within_box();
+            let manifold_ptr = manifold.as_mut().get_unchecked_mut() as *mut b2WorldManifold;
+            contact.as_ref().GetWorldManifold(manifold_ptr);
+
+            manifold
+                .points
+                .iter()
+                .take(point_count as usize)
+                .any(|point| {
+                    let platform_velocity = to_Vec2(
+                        &platform_body
+                            .as_ref()
+                            .GetLinearVelocityFromWorldPoint(point),
+                    );
+                    let other_velocity =
+                        to_Vec2(&other_body.as_ref().GetLinearVelocityFromWorldPoint(point));
+                    (other_velocity - platform_velocity).dot(normal) < LANDING_VELOCITY_TOLERANCE
+                })
+        }
+    }
+}
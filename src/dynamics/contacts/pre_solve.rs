@@ -0,0 +1,158 @@
+use std::fmt::{Debug, Formatter};
+use std::pin::Pin;
+
+use bevy::prelude::Entity;
+
+use libliquidfun_sys::box2d::ffi::b2Contact as ffi_b2Contact;
+
+/// A callback invoked for every touching contact right before it is solved, on every step.
+/// Box2D re-enables the contact before every pre-solve, so disabling only lasts for the current
+/// step. Friction, restitution and tangent speed set through [`b2PreSolveContact`] stay on the
+/// contact until they are changed again or reset, so a hook overriding them for a single step
+/// has to undo the override itself.
+#[allow(non_camel_case_types)]
+pub trait b2PreSolveHook {
+    fn pre_solve(&mut self, contact: &mut b2PreSolveContact);
+}
+
+impl<F: FnMut(&mut b2PreSolveContact)> b2PreSolveHook for F {
+    fn pre_solve(&mut self, contact: &mut b2PreSolveContact) {
+        self(contact)
+    }
+}
+
+/// A contact that is about to be solved. Only lives for the duration of a pre-solve callback.
+#[allow(non_camel_case_types)]
+pub struct b2PreSolveContact<'a> {
+    contact: Pin<&'a mut ffi_b2Contact>,
+    fixture_a: Entity,
+    fixture_b: Entity,
+    body_a: Entity,
+    body_b: Entity,
+}
+
+impl<'a> b2PreSolveContact<'a> {
+    pub(crate) fn new(contact: &'a mut ffi_b2Contact) -> Self {
+        unsafe {
+            let mut contact = Pin::new_unchecked(contact);
+            let mut fixture_a =
+                Pin::new_unchecked(contact.as_mut().GetFixtureA().as_mut().unwrap());
+            let mut fixture_b =
+                Pin::new_unchecked(contact.as_mut().GetFixtureB().as_mut().unwrap());
+            let mut body_a = Pin::new_unchecked(fixture_a.as_mut().GetBody().as_mut().unwrap());
+            let mut body_b = Pin::new_unchecked(fixture_b.as_mut().GetBody().as_mut().unwrap());
+
+            let fixture_a = Entity::from_bits(
+                fixture_a.as_mut().GetUserData().get_unchecked_mut().pointer as u64,
+            );
+            let fixture_b = Entity::from_bits(
+                fixture_b.as_mut().GetUserData().get_unchecked_mut().pointer as u64,
+            );
+            let body_a =
+                Entity::from_bits(body_a.as_mut().GetUserData().get_unchecked_mut().pointer as u64);
+            let body_b =
+                Entity::from_bits(body_b.as_mut().GetUserData().get_unchecked_mut().pointer as u64);
+
+            Self {
+                contact,
+                fixture_a,
+                fixture_b,
+                body_a,
+                body_b,
+            }
+        }
+    }
+
+    pub fn fixture_a(&self) -> Entity {
+        self.fixture_a
+    }
+
+    pub fn fixture_b(&self) -> Entity {
+        self.fixture_b
+    }
+
+    pub fn body_a(&self) -> Entity {
+        self.body_a
+    }
+
+    pub fn body_b(&self) -> Entity {
+        self.body_b
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.contact.as_ref().IsEnabled()
+    }
+
+    /// Enables or disables the contact for the current step; Box2D re-enables contacts before every
+    /// pre-solve.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.contact.as_mut().SetEnabled(enabled);
+    }
+
+    pub fn friction(&self) -> f32 {
+        self.contact.as_ref().GetFriction()
+    }
+
+    /// Overrides the friction until the contact ends or [`Self::reset_friction`] is called.
+    pub fn set_friction(&mut self, friction: f32) {
+        self.contact.as_mut().SetFriction(friction);
+    }
+
+    /// Resets the friction to the mixture of the two fixtures' friction.
+    pub fn reset_friction(&mut self) {
+        self.contact.as_mut().ResetFriction();
+    }
+
+    pub fn restitution(&self) -> f32 {
+        self.contact.as_ref().GetRestitution()
+    }
+
+    /// Overrides the restitution until the contact ends or [`Self::reset_restitution`] is called.
+    pub fn set_restitution(&mut self, restitution: f32) {
+        self.contact.as_mut().SetRestitution(restitution);
+    }
+
+    /// Resets the restitution to the mixture of the two fixtures' restitution.
+    pub fn reset_restitution(&mut self) {
+        self.contact.as_mut().ResetRestitution();
+    }
+
+    pub fn tangent_speed(&self) -> f32 {
+        self.contact.as_ref().GetTangentSpeed()
+    }
+
+    /// Sets the desired tangent speed for a conveyor belt behavior, in meters per second. The speed
+    /// stays until the contact ends or it is set again; contacts start with a speed of zero.
+    pub fn set_tangent_speed(&mut self, speed: f32) {
+        self.contact.as_mut().SetTangentSpeed(speed);
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub(crate) struct b2PreSolveHooks(Vec<Box<dyn b2PreSolveHook>>);
+
+impl b2PreSolveHooks {
+    pub(crate) fn add(&mut self, hook: Box<dyn b2PreSolveHook>) {
+        self.0.push(hook);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn run(&mut self, contact: &mut ffi_b2Contact) {
+        let mut contact = b2PreSolveContact::new(contact);
+        for hook in self.0.iter_mut() {
+            hook.pre_solve(&mut contact);
+        }
+    }
+}
+
+impl Debug for b2PreSolveHooks {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("b2PreSolveHooks")
+            .field(&self.0.len())
+            .finish()
+    }
+}
@@ -28,6 +28,11 @@ impl b2Fixture {
     pub fn def(&self) -> &b2FixtureDef {
         &self.def
     }
+
+    /// Changes the collision filter. Applied to an existing Box2D fixture on the next sync.
+    pub fn set_filter(&mut self, filter: b2Filter) {
+        self.def.filter = filter;
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -67,13 +72,21 @@ impl b2FixtureDef {
         b2fixture_def.restitution = self.restitution;
         b2fixture_def.restitutionThreshold = self.restitution_threshold;
         b2fixture_def.isSensor = self.is_sensor;
-        b2fixture_def.filter.categoryBits = uint16::from(self.filter.category);
-        b2fixture_def.filter.maskBits = uint16::from(self.filter.mask);
-        b2fixture_def.filter.groupIndex = int16::from(self.filter.group_index);
+        b2fixture_def.filter = self.filter.to_ffi();
         return b2fixture_def;
     }
 }
 
+impl b2Filter {
+    pub(crate) fn to_ffi(&self) -> ffi::b2Filter {
+        ffi::b2Filter {
+            categoryBits: uint16::from(self.category),
+            maskBits: uint16::from(self.mask),
+            groupIndex: int16::from(self.group_index),
+        }
+    }
+}
+
 impl Default for b2FixtureDef {
     fn default() -> Self {
         b2FixtureDef {
@@ -0,0 +1,36 @@
+use bevy::prelude::{Component, Entity, Event};
+
+/// Breaks the joint once its reaction force or torque exceeds the given thresholds after a
+/// physics step. The joint is destroyed, its entity despawned and a [`JointBrokeEvent`] sent.
+/// Gear joints referencing the broken joint break along with it and get their own event.
+#[derive(Component, Debug, Copy, Clone)]
+pub struct Breakable {
+    /// The maximum reaction force in N.
+    pub max_force: f32,
+
+    /// The maximum reaction torque in N-m.
+    pub max_torque: f32,
+}
+
+impl Default for Breakable {
+    fn default() -> Self {
+        Self {
+            max_force: f32::INFINITY,
+            max_torque: f32::INFINITY,
+        }
+    }
+}
+
+impl Breakable {
+    pub(crate) fn is_exceeded_by(&self, reaction_force: f32, reaction_torque: f32) -> bool {
+        reaction_force > self.max_force || reaction_torque.abs() > self.max_torque
+    }
+}
+
+#[derive(Event, Debug, Copy, Clone)]
+pub struct JointBrokeEvent {
+    /// The despawned joint entity.
+    pub joint: Entity,
+    pub body_a: Entity,
+    pub body_b: Entity,
+}
@@ -0,0 +1,157 @@
+use std::pin::Pin;
+
+use bevy::ecs::system::EntityCommand;
+use bevy::prelude::{warn, Component, Entity, World};
+use libliquidfun_sys::box2d::ffi;
+
+use crate::dynamics::{b2Joint, b2JointType, b2World, JointPtr};
+
+/// Couples two revolute and/or prismatic joints so that
+/// `coordinate_a + ratio * coordinate_b` stays constant. Unlike the other joints it references
+/// two joint entities; its bodies are the bodyB of each referenced joint.
+///
+/// The referenced joints must stay alive for as long as the gear joint exists. Destroying one of
+/// them, or one of their bodies, also destroys the gear joint and despawns its entity.
+#[allow(non_camel_case_types)]
+#[derive(Component, Debug)]
+pub struct b2GearJoint {
+    /// The first revolute/prismatic joint attached to the gear joint.
+    joint_a: Entity,
+
+    /// The second revolute/prismatic joint attached to the gear joint.
+    joint_b: Entity,
+
+    /// The gear ratio.
+    pub ratio: f32,
+}
+
+impl b2GearJoint {
+    pub fn new(joint_a: Entity, joint_b: Entity, def: &b2GearJointDef) -> Self {
+        Self {
+            joint_a,
+            joint_b,
+            ratio: def.ratio,
+        }
+    }
+
+    pub fn joint_a(&self) -> Entity {
+        self.joint_a
+    }
+
+    pub fn joint_b(&self) -> Entity {
+        self.joint_b
+    }
+
+    /// Returns `None` if either referenced joint doesn't exist in the physics world or is
+    /// neither a revolute nor a prismatic joint.
+    pub(crate) fn create_ffi_joint<'a>(
+        &self,
+        b2_world: &mut b2World,
+        body_a: Entity,
+        body_b: Entity,
+        collide_connected: bool,
+    ) -> Option<JointPtr<'a>> {
+        for joint in [self.joint_a, self.joint_b] {
+            match b2_world.get_joint_ptr(&joint) {
+                Some(JointPtr::Revolute(_) | JointPtr::Prismatic(_)) => {}
+                Some(_) => {
+                    warn!(
+                        "Gear joint references a joint that is neither revolute nor prismatic: {:?}",
+                        joint
+                    );
+                    return None;
+                }
+                None => {
+                    warn!(
+                        "Gear joint references a joint that doesn't exist in the physics world: {:?}",
+                        joint
+                    );
+                    return None;
+                }
+            }
+        }
+
+        unsafe {
+            let body_a = b2_world.get_body_ptr_mut(body_a).unwrap().as_mut();
+            let body_a = body_a.get_unchecked_mut() as *mut ffi::b2Body;
+            let body_b = b2_world.get_body_ptr_mut(body_b).unwrap().as_mut();
+            let body_b = body_b.get_unchecked_mut() as *mut ffi::b2Body;
+            let joint_a = b2_world.get_joint_ptr(&self.joint_a)?.as_ffi_joint();
+            let joint_b = b2_world.get_joint_ptr(&self.joint_b)?.as_ffi_joint();
+            let ffi_world = b2_world.get_world_ptr().as_mut();
+            let ffi_joint = ffi::CreateGearJoint(
+                ffi_world,
+                body_a,
+                body_b,
+                collide_connected,
+                joint_a,
+                joint_b,
+                self.ratio,
+            );
+            let ffi_joint = Pin::new_unchecked(ffi_joint.as_mut().unwrap());
+            Some(JointPtr::Gear(ffi_joint))
+        }
+    }
+
+    pub(crate) fn sync_to_world(&self, mut joint_ptr: Pin<&mut ffi::b2GearJoint>) {
+        joint_ptr.as_mut().SetRatio(self.ratio);
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct b2GearJointDef {
+    pub ratio: f32,
+}
+
+impl Default for b2GearJointDef {
+    fn default() -> Self {
+        Self { ratio: 1. }
+    }
+}
+
+/// Creates a gear joint between two existing revolute/prismatic joint entities. The joint
+/// entities must already have their [`b2Joint`] component, e.g. by adding their create command
+/// before this one; otherwise no gear joint is created.
+pub struct CreateGearJoint {
+    joint_a: Entity,
+    joint_b: Entity,
+    collide_connected: bool,
+    def: b2GearJointDef,
+}
+
+impl CreateGearJoint {
+    pub fn new(
+        joint_a: Entity,
+        joint_b: Entity,
+        collide_connected: bool,
+        def: &b2GearJointDef,
+    ) -> Self {
+        Self {
+            joint_a,
+            joint_b,
+            collide_connected,
+            def: def.clone(),
+        }
+    }
+}
+
+impl EntityCommand for CreateGearJoint {
+    fn apply(self, id: Entity, world: &mut World) {
+        let (Some(joint_a), Some(joint_b)) = (
+            world.get::<b2Joint>(self.joint_a),
+            world.get::<b2Joint>(self.joint_b),
+        ) else {
+            warn!(
+                "Gear joint references an entity without a b2Joint: {:?}, {:?}",
+                self.joint_a, self.joint_b
+            );
+            return;
+        };
+        let body_a = *joint_a.body_b();
+        let body_b = *joint_b.body_b();
+        let joint = b2Joint::new(b2JointType::Gear, body_a, body_b, self.collide_connected);
+        let gear_joint = b2GearJoint::new(self.joint_a, self.joint_b, &self.def);
+        world.entity_mut(id).insert((joint, gear_joint));
+    }
+}
@@ -1,8 +1,11 @@
 use std::pin::Pin;
 
+use bevy::math::Vec2;
 use bevy::prelude::{Component, Entity};
 use libliquidfun_sys::box2d::ffi;
 
+use crate::internal::to_Vec2;
+
 #[allow(non_camel_case_types)]
 #[derive(Component, Debug)]
 pub struct b2Joint {
@@ -44,20 +47,84 @@ impl b2Joint {
     }
 }
 
+/// Opt-in read-back of a joint's state, updated after every frame's physics steps. Values that
+/// don't apply to the joint's type stay at zero.
+#[derive(Component, Debug, Default, Copy, Clone)]
+pub struct JointState {
+    angle: f32,
+    translation: f32,
+    speed: f32,
+    angular_speed: f32,
+    motor_torque: f32,
+    motor_force: f32,
+    length: f32,
+    reaction_force: Vec2,
+    reaction_torque: f32,
+}
+
+impl JointState {
+    /// The angle of bodyB relative to bodyA in radians, for revolute and wheel joints.
+    pub fn angle(&self) -> f32 {
+        self.angle
+    }
+
+    /// The translation along the joint axis, for prismatic and wheel joints.
+    pub fn translation(&self) -> f32 {
+        self.translation
+    }
+
+    /// The angular speed for revolute joints, or the linear speed along the axis for prismatic
+    /// and wheel joints.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// The angular speed of bodyB relative to bodyA in radians per second, for revolute and
+    /// wheel joints.
+    pub fn angular_speed(&self) -> f32 {
+        self.angular_speed
+    }
+
+    /// The motor torque of the last step in N-m, for revolute and wheel joints.
+    pub fn motor_torque(&self) -> f32 {
+        self.motor_torque
+    }
+
+    /// The motor force of the last step in N, for prismatic joints.
+    pub fn motor_force(&self) -> f32 {
+        self.motor_force
+    }
+
+    /// The current length of distance joints.
+    pub fn length(&self) -> f32 {
+        self.length
+    }
+
+    /// The reaction force on bodyB at the joint anchor of the last step, in Newtons.
+    pub fn reaction_force(&self) -> Vec2 {
+        self.reaction_force
+    }
+
+    /// The reaction torque on bodyB of the last step, in N*m.
+    pub fn reaction_torque(&self) -> f32 {
+        self.reaction_torque
+    }
+}
+
 #[allow(non_camel_case_types)]
 #[derive(Debug)]
 pub enum b2JointType {
     Revolute,
     Prismatic,
     Distance,
-    _Pulley,
+    Pulley,
     Mouse,
-    _Gear,
-    _Wheel,
-    _Weld,
-    _Friction,
+    Gear,
+    Wheel,
+    Weld,
+    Friction,
     _Rope,
-    _Motor,
+    Motor,
     _Area,
 }
 
@@ -65,13 +132,93 @@ pub(crate) enum JointPtr<'a> {
     Revolute(Pin<&'a mut ffi::b2RevoluteJoint>),
     Prismatic(Pin<&'a mut ffi::b2PrismaticJoint>),
     Distance(Pin<&'a mut ffi::b2DistanceJoint>),
-    _Pulley,
+    Pulley(Pin<&'a mut ffi::b2PulleyJoint>),
     Mouse(Pin<&'a mut ffi::b2MouseJoint>),
-    _Gear,
-    _Wheel,
-    _Weld,
-    _Friction,
-    _Rope,
-    _Motor,
-    _Area,
+    Gear(Pin<&'a mut ffi::b2GearJoint>),
+    Wheel(Pin<&'a mut ffi::b2WheelJoint>),
+    Weld(Pin<&'a mut ffi::b2WeldJoint>),
+    Friction(Pin<&'a mut ffi::b2FrictionJoint>),
+    Motor(Pin<&'a mut ffi::b2MotorJoint>),
+}
+
+impl<'a> JointPtr<'a> {
+    pub(crate) fn read_state(&mut self, inv_dt: f32) -> JointState {
+        let mut state = JointState::default();
+        match self {
+            JointPtr::Revolute(joint) => {
+                state.angle = joint.as_ref().GetJointAngle();
+                state.speed = joint.as_ref().GetJointSpeed();
+                state.angular_speed = state.speed;
+                state.motor_torque = joint.as_ref().GetMotorTorque(inv_dt);
+            }
+            JointPtr::Prismatic(joint) => {
+                state.translation = joint.as_ref().GetJointTranslation();
+                state.speed = joint.as_ref().GetJointSpeed();
+                state.motor_force = joint.as_ref().GetMotorForce(inv_dt);
+            }
+            JointPtr::Wheel(joint) => {
+                state.angle = joint.as_ref().GetJointAngle();
+                state.translation = joint.as_ref().GetJointTranslation();
+                state.speed = joint.as_ref().GetJointLinearSpeed();
+                state.angular_speed = joint.as_ref().GetJointAngularSpeed();
+                state.motor_torque = joint.as_ref().GetMotorTorque(inv_dt);
+            }
+            JointPtr::Distance(joint) => {
+                state.length = joint.as_ref().GetCurrentLength();
+            }
+            _ => {}
+        }
+
+        (state.reaction_force, state.reaction_torque) = self.reaction(inv_dt);
+        state
+    }
+
+    /// The reaction force and torque on bodyB of the last step.
+    pub(crate) fn reaction(&mut self, inv_dt: f32) -> (Vec2, f32) {
+        unsafe {
+            let joint = Pin::new_unchecked(&mut *self.as_ffi_joint());
+            (
+                to_Vec2(&joint.as_ref().GetReactionForce(inv_dt)),
+                joint.as_ref().GetReactionTorque(inv_dt),
+            )
+        }
+    }
+
+    /// The joint as its `b2Joint` base class, e.g. for `b2World::DestroyJoint`.
+    pub(crate) fn as_ffi_joint(&mut self) -> *mut ffi::b2Joint {
+        unsafe {
+            match self {
+                JointPtr::Revolute(joint) => joint.as_mut().get_unchecked_mut()
+                    as *mut ffi::b2RevoluteJoint
+                    as *mut ffi::b2Joint,
+                JointPtr::Prismatic(joint) => joint.as_mut().get_unchecked_mut()
+                    as *mut ffi::b2PrismaticJoint
+                    as *mut ffi::b2Joint,
+                JointPtr::Distance(joint) => joint.as_mut().get_unchecked_mut()
+                    as *mut ffi::b2DistanceJoint
+                    as *mut ffi::b2Joint,
+                JointPtr::Mouse(joint) => joint.as_mut().get_unchecked_mut()
+                    as *mut ffi::b2MouseJoint
+                    as *mut ffi::b2Joint,
+                JointPtr::Weld(joint) => {
+                    joint.as_mut().get_unchecked_mut() as *mut ffi::b2WeldJoint as *mut ffi::b2Joint
+                }
+                JointPtr::Wheel(joint) => joint.as_mut().get_unchecked_mut()
+                    as *mut ffi::b2WheelJoint
+                    as *mut ffi::b2Joint,
+                JointPtr::Motor(joint) => joint.as_mut().get_unchecked_mut()
+                    as *mut ffi::b2MotorJoint
+                    as *mut ffi::b2Joint,
+                JointPtr::Friction(joint) => joint.as_mut().get_unchecked_mut()
+                    as *mut ffi::b2FrictionJoint
+                    as *mut ffi::b2Joint,
+                JointPtr::Pulley(joint) => joint.as_mut().get_unchecked_mut()
+                    as *mut ffi::b2PulleyJoint
+                    as *mut ffi::b2Joint,
+                JointPtr::Gear(joint) => {
+                    joint.as_mut().get_unchecked_mut() as *mut ffi::b2GearJoint as *mut ffi::b2Joint
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,141 @@
+use std::pin::Pin;
+
+use bevy::ecs::system::EntityCommand;
+use bevy::math::Vec2;
+use bevy::prelude::{Component, Entity, World};
+use libliquidfun_sys::box2d::ffi;
+
+use crate::dynamics::{b2Joint, b2JointType, b2World, JointPtr};
+use crate::internal::to_b2Vec2;
+
+/// Drives the relative position and rotation of bodyB towards a target offset from bodyA, with
+/// bounded force and torque. Useful for moving characters around in top-down games.
+#[allow(non_camel_case_types)]
+#[derive(Component, Debug)]
+pub struct b2MotorJoint {
+    /// Position of bodyB minus the position of bodyA, in bodyA's frame, in meters.
+    pub linear_offset: Vec2,
+
+    /// The bodyB angle minus bodyA angle in radians.
+    pub angular_offset: f32,
+
+    /// The maximum motor force in N.
+    pub max_force: f32,
+
+    /// The maximum motor torque in N-m.
+    pub max_torque: f32,
+
+    /// Position correction factor in the range [0,1].
+    pub correction_factor: f32,
+}
+
+impl b2MotorJoint {
+    pub fn new(def: &b2MotorJointDef) -> Self {
+        Self {
+            linear_offset: def.linear_offset,
+            angular_offset: def.angular_offset,
+            max_force: def.max_force,
+            max_torque: def.max_torque,
+            correction_factor: def.correction_factor,
+        }
+    }
+
+    pub(crate) fn create_ffi_joint<'a>(
+        &self,
+        b2_world: &mut b2World,
+        body_a: Entity,
+        body_b: Entity,
+        collide_connected: bool,
+    ) -> JointPtr<'a> {
+        unsafe {
+            let body_a = b2_world.get_body_ptr_mut(body_a).unwrap().as_mut();
+            let body_a = body_a.get_unchecked_mut() as *mut ffi::b2Body;
+            let body_b = b2_world.get_body_ptr_mut(body_b).unwrap().as_mut();
+            let body_b = body_b.get_unchecked_mut() as *mut ffi::b2Body;
+            let ffi_world = b2_world.get_world_ptr().as_mut();
+            let ffi_joint = ffi::CreateMotorJoint(
+                ffi_world,
+                body_a,
+                body_b,
+                collide_connected,
+                to_b2Vec2(&self.linear_offset),
+                self.angular_offset,
+                self.max_force,
+                self.max_torque,
+                self.correction_factor,
+            );
+            let ffi_joint = Pin::new_unchecked(ffi_joint.as_mut().unwrap());
+            JointPtr::Motor(ffi_joint)
+        }
+    }
+
+    pub(crate) fn sync_to_world(&self, mut joint_ptr: Pin<&mut ffi::b2MotorJoint>) {
+        joint_ptr
+            .as_mut()
+            .SetLinearOffset(&to_b2Vec2(&self.linear_offset));
+        joint_ptr.as_mut().SetAngularOffset(self.angular_offset);
+        joint_ptr.as_mut().SetMaxForce(self.max_force);
+        joint_ptr.as_mut().SetMaxTorque(self.max_torque);
+        joint_ptr
+            .as_mut()
+            .SetCorrectionFactor(self.correction_factor);
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct b2MotorJointDef {
+    pub linear_offset: Vec2,
+    pub angular_offset: f32,
+    pub max_force: f32,
+    pub max_torque: f32,
+    pub correction_factor: f32,
+}
+
+impl Default for b2MotorJointDef {
+    fn default() -> Self {
+        Self {
+            linear_offset: Vec2::ZERO,
+            angular_offset: 0.,
+            max_force: 1.,
+            max_torque: 1.,
+            correction_factor: 0.3,
+        }
+    }
+}
+
+pub struct CreateMotorJoint {
+    body_a: Entity,
+    body_b: Entity,
+    collide_connected: bool,
+    def: b2MotorJointDef,
+}
+
+impl CreateMotorJoint {
+    pub fn new(
+        body_a: Entity,
+        body_b: Entity,
+        collide_connected: bool,
+        def: &b2MotorJointDef,
+    ) -> Self {
+        Self {
+            body_a,
+            body_b,
+            collide_connected,
+            def: def.clone(),
+        }
+    }
+}
+
+impl EntityCommand for CreateMotorJoint {
+    fn apply(self, id: Entity, world: &mut World) {
+        let joint = b2Joint::new(
+            b2JointType::Motor,
+            self.body_a,
+            self.body_b,
+            self.collide_connected,
+        );
+        let motor_joint = b2MotorJoint::new(&self.def);
+        world.entity_mut(id).insert((joint, motor_joint));
+    }
+}
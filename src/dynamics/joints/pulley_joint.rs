@@ -0,0 +1,155 @@
+use std::pin::Pin;
+
+use bevy::ecs::system::EntityCommand;
+use bevy::math::Vec2;
+use bevy::prelude::{Component, Entity, World};
+use libliquidfun_sys::box2d::ffi;
+
+use crate::dynamics::{b2Joint, b2JointType, b2World, JointPtr};
+use crate::internal::to_b2Vec2;
+
+/// Connects two bodies to the ground and to each other, so that
+/// `length_a + ratio * length_b` stays constant.
+#[allow(non_camel_case_types)]
+#[derive(Component, Debug)]
+pub struct b2PulleyJoint {
+    /// The first ground anchor in world coordinates. This point never moves.
+    ground_anchor_a: Vec2,
+
+    /// The second ground anchor in world coordinates. This point never moves.
+    ground_anchor_b: Vec2,
+
+    /// The local anchor point relative to bodyA's origin.
+    local_anchor_a: Vec2,
+
+    /// The local anchor point relative to bodyB's origin.
+    local_anchor_b: Vec2,
+
+    /// The reference length for the segment attached to bodyA.
+    length_a: f32,
+
+    /// The reference length for the segment attached to bodyB.
+    length_b: f32,
+
+    /// The pulley ratio, used to simulate a block-and-tackle.
+    ratio: f32,
+}
+
+impl b2PulleyJoint {
+    pub fn new(def: &b2PulleyJointDef) -> Self {
+        Self {
+            ground_anchor_a: def.ground_anchor_a,
+            ground_anchor_b: def.ground_anchor_b,
+            local_anchor_a: def.local_anchor_a,
+            local_anchor_b: def.local_anchor_b,
+            length_a: def.length_a,
+            length_b: def.length_b,
+            ratio: def.ratio,
+        }
+    }
+
+    pub fn ground_anchor_a(&self) -> Vec2 {
+        self.ground_anchor_a
+    }
+
+    pub fn ground_anchor_b(&self) -> Vec2 {
+        self.ground_anchor_b
+    }
+
+    pub fn ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    pub(crate) fn create_ffi_joint<'a>(
+        &self,
+        b2_world: &mut b2World,
+        body_a: Entity,
+        body_b: Entity,
+        collide_connected: bool,
+    ) -> JointPtr<'a> {
+        unsafe {
+            let body_a = b2_world.get_body_ptr_mut(body_a).unwrap().as_mut();
+            let body_a = body_a.get_unchecked_mut() as *mut ffi::b2Body;
+            let body_b = b2_world.get_body_ptr_mut(body_b).unwrap().as_mut();
+            let body_b = body_b.get_unchecked_mut() as *mut ffi::b2Body;
+            let ffi_world = b2_world.get_world_ptr().as_mut();
+            let ffi_joint = ffi::CreatePulleyJoint(
+                ffi_world,
+                body_a,
+                body_b,
+                collide_connected,
+                to_b2Vec2(&self.ground_anchor_a),
+                to_b2Vec2(&self.ground_anchor_b),
+                to_b2Vec2(&self.local_anchor_a),
+                to_b2Vec2(&self.local_anchor_b),
+                self.length_a,
+                self.length_b,
+                self.ratio,
+            );
+            let ffi_joint = Pin::new_unchecked(ffi_joint.as_mut().unwrap());
+            JointPtr::Pulley(ffi_joint)
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone)]
+pub struct b2PulleyJointDef {
+    pub ground_anchor_a: Vec2,
+    pub ground_anchor_b: Vec2,
+    pub local_anchor_a: Vec2,
+    pub local_anchor_b: Vec2,
+    pub length_a: f32,
+    pub length_b: f32,
+    pub ratio: f32,
+}
+
+impl Default for b2PulleyJointDef {
+    fn default() -> Self {
+        Self {
+            ground_anchor_a: Vec2::new(-1., 1.),
+            ground_anchor_b: Vec2::new(1., 1.),
+            local_anchor_a: Vec2::new(-1., 0.),
+            local_anchor_b: Vec2::new(1., 0.),
+            length_a: 0.,
+            length_b: 0.,
+            ratio: 1.,
+        }
+    }
+}
+
+pub struct CreatePulleyJoint {
+    body_a: Entity,
+    body_b: Entity,
+    collide_connected: bool,
+    def: b2PulleyJointDef,
+}
+
+impl CreatePulleyJoint {
+    pub fn new(
+        body_a: Entity,
+        body_b: Entity,
+        collide_connected: bool,
+        def: &b2PulleyJointDef,
+    ) -> Self {
+        Self {
+            body_a,
+            body_b,
+            collide_connected,
+            def: def.clone(),
+        }
+    }
+}
+
+impl EntityCommand for CreatePulleyJoint {
+    fn apply(self, id: Entity, world: &mut World) {
+        let joint = b2Joint::new(
+            b2JointType::Pulley,
+            self.body_a,
+            self.body_b,
+            self.collide_connected,
+        );
+        let pulley_joint = b2PulleyJoint::new(&self.def);
+        world.entity_mut(id).insert((joint, pulley_joint));
+    }
+}
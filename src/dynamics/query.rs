@@ -0,0 +1,40 @@
+use std::pin::Pin;
+
+use bevy::prelude::*;
+
+use libliquidfun_sys::box2d::ffi::b2Fixture as ffi_b2Fixture;
+use libliquidfun_sys::box2d::ffi::{b2ParticleSystem, b2QueryCallbackImpl};
+
+/// Collects the entities of all fixtures whose AABB overlaps the queried AABB.
+#[derive(Debug, Default)]
+#[allow(non_camel_case_types)]
+pub(crate) struct b2QueryFixtures {
+    fixtures: Vec<Entity>,
+}
+
+impl b2QueryFixtures {
+    pub fn extract_hits(self) -> Vec<Entity> {
+        self.fixtures
+    }
+}
+
+impl b2QueryCallbackImpl for b2QueryFixtures {
+    fn report_fixture(&mut self, fixture: &mut ffi_b2Fixture) -> bool {
+        unsafe {
+            let mut ffi_fixture = Pin::new_unchecked(fixture);
+            let user_data = ffi_fixture.as_mut().GetUserData();
+            let pointer_to_entity_bits = user_data.get_unchecked_mut().pointer;
+            self.fixtures
+                .push(Entity::from_bits(pointer_to_entity_bits as u64));
+        }
+        true
+    }
+
+    fn report_particle(&mut self, _particle_system: &b2ParticleSystem, _index: i32) -> bool {
+        false
+    }
+
+    fn should_query_particle_system(&mut self, _particle_system: *const b2ParticleSystem) -> bool {
+        false
+    }
+}
@@ -0,0 +1,204 @@
+use bevy::prelude::*;
+
+use crate::collision::b2Shape;
+use crate::dynamics::{
+    b2BodyBundle, b2BodyDef, b2BodyType, b2DistanceJointDef, b2Fixture, b2FixtureDef,
+    b2RevoluteJointDef, CreateDistanceJoint, CreateRevoluteJoint,
+};
+use crate::utils::DebugDrawFixtures;
+
+/// How consecutive rope segments are linked together.
+#[derive(Debug, Copy, Clone)]
+pub enum RopeJointType {
+    /// Segments hinge around their shared end points.
+    Revolute,
+    /// Segments may drift apart up to `max_length` at their shared end points, which gives a
+    /// slacker, more elastic rope.
+    Distance { max_length: f32 },
+}
+
+/// The result of [`RopeBuilder::spawn`]. Also inserted on the rope's root entity.
+///
+/// The segment bodies are children of the root entity, so despawning the root recursively
+/// removes the whole rope, including the fixtures and joints of its segments.
+#[derive(Component, Debug, Clone)]
+pub struct Rope {
+    root: Entity,
+    segments: Vec<Entity>,
+}
+
+impl Rope {
+    pub fn root(&self) -> Entity {
+        self.root
+    }
+
+    /// The segment bodies, from the start to the end of the rope.
+    pub fn segments(&self) -> &[Entity] {
+        &self.segments
+    }
+}
+
+/// Spawns a rope of box segments between two points, optionally attached to bodies at either
+/// end.
+#[derive(Debug, Clone)]
+pub struct RopeBuilder {
+    start: Vec2,
+    end: Vec2,
+    segment_count: usize,
+    thickness: f32,
+    density: f32,
+    joint_type: RopeJointType,
+    start_attachment: Option<(Entity, Vec2)>,
+    end_attachment: Option<(Entity, Vec2)>,
+    debug_draw: Option<DebugDrawFixtures>,
+}
+
+impl RopeBuilder {
+    pub fn new(start: Vec2, end: Vec2) -> Self {
+        Self {
+            start,
+            end,
+            segment_count: 10,
+            thickness: 0.125,
+            density: 20.,
+            joint_type: RopeJointType::Revolute,
+            start_attachment: None,
+            end_attachment: None,
+            debug_draw: None,
+        }
+    }
+
+    pub fn segment_count(mut self, segment_count: usize) -> Self {
+        assert!(segment_count > 0, "A rope needs at least one segment");
+        self.segment_count = segment_count;
+        self
+    }
+
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    pub fn density(mut self, density: f32) -> Self {
+        self.density = density;
+        self
+    }
+
+    pub fn joint_type(mut self, joint_type: RopeJointType) -> Self {
+        self.joint_type = joint_type;
+        self
+    }
+
+    /// Attaches the start of the rope to a body, at a point relative to the body's origin.
+    pub fn attach_start(mut self, body: Entity, local_anchor: Vec2) -> Self {
+        self.start_attachment = Some((body, local_anchor));
+        self
+    }
+
+    /// Attaches the end of the rope to a body, at a point relative to the body's origin.
+    pub fn attach_end(mut self, body: Entity, local_anchor: Vec2) -> Self {
+        self.end_attachment = Some((body, local_anchor));
+        self
+    }
+
+    pub fn debug_draw(mut self, debug_draw: DebugDrawFixtures) -> Self {
+        self.debug_draw = Some(debug_draw);
+        self
+    }
+
+    pub fn spawn(&self, commands: &mut Commands) -> Rope {
+        let direction = self.end - self.start;
+        let segment_length = direction.length() / self.segment_count as f32;
+        let angle = direction.y.atan2(direction.x);
+        let half_length = 0.5 * segment_length;
+
+        let root = commands.spawn(TransformBundle::default()).id();
+
+        let fixture_def = b2FixtureDef::new(
+            b2Shape::create_box(half_length, 0.5 * self.thickness),
+            self.density,
+        );
+        let segments: Vec<Entity> = (0..self.segment_count)
+            .map(|i| {
+                let body_def = b2BodyDef {
+                    body_type: b2BodyType::Dynamic,
+                    position: self.start + direction * (i as f32 + 0.5) / self.segment_count as f32,
+                    angle,
+                    ..default()
+                };
+                let segment = commands.spawn(b2BodyBundle::new(&body_def)).id();
+                commands.entity(root).add_child(segment);
+
+                let mut fixture = commands.spawn(b2Fixture::new(segment, &fixture_def));
+                if let Some(debug_draw) = &self.debug_draw {
+                    fixture.insert(debug_draw.clone());
+                }
+                segment
+            })
+            .collect();
+
+        let segment_start = Vec2::new(-half_length, 0.);
+        let segment_end = Vec2::new(half_length, 0.);
+        for pair in segments.windows(2) {
+            self.spawn_joint(commands, root, pair[0], segment_end, pair[1], segment_start);
+        }
+        if let Some((body, local_anchor)) = self.start_attachment {
+            self.spawn_joint(
+                commands,
+                root,
+                body,
+                local_anchor,
+                segments[0],
+                segment_start,
+            );
+        }
+        if let Some((body, local_anchor)) = self.end_attachment {
+            let last_segment = *segments.last().unwrap();
+            self.spawn_joint(
+                commands,
+                root,
+                last_segment,
+                segment_end,
+                body,
+                local_anchor,
+            );
+        }
+
+        let rope = Rope { root, segments };
+        commands.entity(root).insert(rope.clone());
+        rope
+    }
+
+    fn spawn_joint(
+        &self,
+        commands: &mut Commands,
+        root: Entity,
+        body_a: Entity,
+        local_anchor_a: Vec2,
+        body_b: Entity,
+        local_anchor_b: Vec2,
+    ) {
+        let mut joint = commands.spawn_empty();
+        match self.joint_type {
+            RopeJointType::Revolute => {
+                let def = b2RevoluteJointDef {
+                    local_anchor_a,
+                    local_anchor_b,
+                    ..default()
+                };
+                joint.add(CreateRevoluteJoint::new(body_a, body_b, false, &def));
+            }
+            RopeJointType::Distance { max_length } => {
+                let def = b2DistanceJointDef {
+                    local_anchor_a,
+                    local_anchor_b,
+                    max_length,
+                    ..default()
+                };
+                joint.add(CreateDistanceJoint::new(body_a, body_b, false, &def));
+            }
+        }
+        let joint = joint.id();
+        commands.entity(root).add_child(joint);
+    }
+}
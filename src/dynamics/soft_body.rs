@@ -0,0 +1,372 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+
+use crate::collision::b2Shape;
+use crate::dynamics::{
+    b2BodyBundle, b2BodyDef, b2BodyType, b2DistanceJointDef, b2Fixture, b2FixtureDef,
+    CreateDistanceJoint,
+};
+use crate::utils::DebugDrawFixtures;
+
+/// The arrangement of the nodes of a soft body.
+#[derive(Debug, Copy, Clone)]
+pub enum SoftBodyShape {
+    /// Nodes on a circle, each linked to its two nearest neighbors on either side. Pair with
+    /// [`SoftBodyBuilder::pressure`] for blobs that keep their volume.
+    Ring {
+        center: Vec2,
+        radius: f32,
+        node_count: usize,
+    },
+    /// A rectangle of `columns` x `rows` nodes, linked horizontally, vertically and
+    /// diagonally.
+    Grid {
+        center: Vec2,
+        size: Vec2,
+        columns: usize,
+        rows: usize,
+    },
+}
+
+impl SoftBodyShape {
+    fn node_positions(&self) -> Vec<Vec2> {
+        match *self {
+            SoftBodyShape::Ring {
+                center,
+                radius,
+                node_count,
+            } => (0..node_count)
+                .map(|i| {
+                    let angle = TAU * i as f32 / node_count as f32;
+                    center + radius * Vec2::new(angle.cos(), angle.sin())
+                })
+                .collect(),
+            SoftBodyShape::Grid {
+                center,
+                size,
+                columns,
+                rows,
+            } => {
+                let lower_left = center - 0.5 * size;
+                let spacing = size / Vec2::new((columns - 1) as f32, (rows - 1) as f32);
+                (0..rows)
+                    .flat_map(|row| {
+                        (0..columns).map(move |column| {
+                            lower_left + spacing * Vec2::new(column as f32, row as f32)
+                        })
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Pairs of node indices linked by a spring.
+    fn links(&self) -> Vec<(usize, usize)> {
+        match *self {
+            SoftBodyShape::Ring { node_count, .. } => {
+                // in small rings the skip links coincide with neighbour links or with each
+                // other, and a duplicated spring would double the stiffness of that link
+                let mut links = Vec::new();
+                for i in 0..node_count {
+                    for j in [(i + 1) % node_count, (i + 2) % node_count] {
+                        let link = (i.min(j), i.max(j));
+                        if i != j && !links.contains(&link) {
+                            links.push(link);
+                        }
+                    }
+                }
+                links
+            }
+            SoftBodyShape::Grid { columns, rows, .. } => {
+                let index = |column: usize, row: usize| row * columns + column;
+                let mut links = Vec::new();
+                for row in 0..rows {
+                    for column in 0..columns {
+                        if column + 1 < columns {
+                            links.push((index(column, row), index(column + 1, row)));
+                        }
+                        if row + 1 < rows {
+                            links.push((index(column, row), index(column, row + 1)));
+                        }
+                        if column + 1 < columns && row + 1 < rows {
+                            links.push((index(column, row), index(column + 1, row + 1)));
+                            links.push((index(column + 1, row), index(column, row + 1)));
+                        }
+                    }
+                }
+                links
+            }
+        }
+    }
+
+    fn triangle_indices(&self) -> Vec<u32> {
+        match *self {
+            // a fan around an extra center vertex, stored after the nodes
+            SoftBodyShape::Ring { node_count, .. } => (0..node_count)
+                .flat_map(|i| [node_count, i, (i + 1) % node_count])
+                .map(|i| i as u32)
+                .collect(),
+            SoftBodyShape::Grid { columns, rows, .. } => {
+                let index = |column: usize, row: usize| (row * columns + column) as u32;
+                (0..rows - 1)
+                    .flat_map(|row| {
+                        (0..columns - 1).flat_map(move |column| {
+                            [
+                                index(column, row),
+                                index(column + 1, row),
+                                index(column + 1, row + 1),
+                                index(column, row),
+                                index(column + 1, row + 1),
+                                index(column, row + 1),
+                            ]
+                        })
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    fn mesh_positions(&self, node_positions: &[Vec2]) -> Vec<[f32; 3]> {
+        let mut positions: Vec<[f32; 3]> = node_positions
+            .iter()
+            .map(|position| position.extend(0.).to_array())
+            .collect();
+        if let SoftBodyShape::Ring { .. } = self {
+            let center = node_positions.iter().sum::<Vec2>() / node_positions.len() as f32;
+            positions.push(center.extend(0.).to_array());
+        }
+        positions
+    }
+
+    fn mesh_uvs(&self) -> Vec<[f32; 2]> {
+        match *self {
+            SoftBodyShape::Ring { node_count, .. } => (0..node_count)
+                .map(|i| {
+                    let angle = TAU * i as f32 / node_count as f32;
+                    [0.5 + 0.5 * angle.cos(), 0.5 - 0.5 * angle.sin()]
+                })
+                .chain([[0.5, 0.5]])
+                .collect(),
+            SoftBodyShape::Grid { columns, rows, .. } => (0..rows)
+                .flat_map(|row| {
+                    (0..columns).map(move |column| {
+                        [
+                            column as f32 / (columns - 1) as f32,
+                            1. - row as f32 / (rows - 1) as f32,
+                        ]
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The result of [`SoftBodyBuilder::spawn`]. Also inserted on the soft body's root entity.
+///
+/// The node bodies and the springs between them are children of the root entity, so despawning
+/// the root recursively removes the whole soft body. Adding a `Mesh2dHandle` to the root, e.g.
+/// through a `MaterialMesh2dBundle` with the mesh from [`SoftBodyBuilder::build_mesh`], keeps the
+/// mesh deformed along with the nodes.
+#[derive(Component, Debug, Clone)]
+pub struct SoftBody {
+    root: Entity,
+    nodes: Vec<Entity>,
+    shape: SoftBodyShape,
+    rest_area: f32,
+
+    /// Strength of the force pushing the nodes of a ring outwards when it is squeezed below its
+    /// rest area.
+    pub pressure: f32,
+}
+
+impl SoftBody {
+    pub fn root(&self) -> Entity {
+        self.root
+    }
+
+    /// The node bodies, in the order of the mesh vertices.
+    pub fn nodes(&self) -> &[Entity] {
+        &self.nodes
+    }
+
+    pub fn shape(&self) -> &SoftBodyShape {
+        &self.shape
+    }
+
+    /// The pressure forces on each node, given the current node positions. Zero for grids.
+    pub(crate) fn pressure_forces(&self, node_positions: &[Vec2]) -> Vec<Vec2> {
+        let mut forces = vec![Vec2::ZERO; node_positions.len()];
+        if !matches!(self.shape, SoftBodyShape::Ring { .. }) || self.pressure == 0. {
+            return forces;
+        }
+
+        let area = polygon_area(node_positions);
+        let deficit = self.rest_area - area;
+        let node_count = node_positions.len();
+        for i in 0..node_count {
+            let next = (i + 1) % node_count;
+            let edge = node_positions[next] - node_positions[i];
+            // the nodes run counter-clockwise, so the outward normal is on the right of each edge
+            let force = 0.5 * self.pressure * deficit * Vec2::new(edge.y, -edge.x);
+            forces[i] += force;
+            forces[next] += force;
+        }
+        forces
+    }
+
+    pub(crate) fn update_mesh(&self, mesh: &mut Mesh, node_positions: &[Vec2]) {
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            self.shape.mesh_positions(node_positions),
+        );
+    }
+}
+
+fn polygon_area(vertices: &[Vec2]) -> f32 {
+    let vertex_count = vertices.len();
+    0.5 * (0..vertex_count)
+        .map(|i| vertices[i].perp_dot(vertices[(i + 1) % vertex_count]))
+        .sum::<f32>()
+}
+
+/// Spawns a soft body made of small circular bodies linked by distance joint springs.
+#[derive(Debug, Clone)]
+pub struct SoftBodyBuilder {
+    shape: SoftBodyShape,
+    node_radius: f32,
+    density: f32,
+    stiffness: f32,
+    damping: f32,
+    pressure: f32,
+    debug_draw: Option<DebugDrawFixtures>,
+}
+
+impl SoftBodyBuilder {
+    pub fn new(shape: SoftBodyShape) -> Self {
+        match shape {
+            SoftBodyShape::Ring { node_count, .. } => {
+                assert!(node_count >= 3, "A soft body ring needs at least 3 nodes")
+            }
+            SoftBodyShape::Grid { columns, rows, .. } => assert!(
+                columns >= 2 && rows >= 2,
+                "A soft body grid needs at least 2 columns and 2 rows"
+            ),
+        }
+        Self {
+            shape,
+            node_radius: 0.25,
+            density: 1.,
+            stiffness: 100.,
+            damping: 1.,
+            pressure: 0.,
+            debug_draw: None,
+        }
+    }
+
+    pub fn node_radius(mut self, node_radius: f32) -> Self {
+        self.node_radius = node_radius;
+        self
+    }
+
+    pub fn density(mut self, density: f32) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// The spring stiffness in N/m.
+    pub fn stiffness(mut self, stiffness: f32) -> Self {
+        self.stiffness = stiffness;
+        self
+    }
+
+    /// The spring damping in N*s/m.
+    pub fn damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// See [`SoftBody::pressure`]. Only applies to rings.
+    pub fn pressure(mut self, pressure: f32) -> Self {
+        self.pressure = pressure;
+        self
+    }
+
+    pub fn debug_draw(mut self, debug_draw: DebugDrawFixtures) -> Self {
+        self.debug_draw = Some(debug_draw);
+        self
+    }
+
+    /// A mesh covering the soft body in its rest shape, in world coordinates.
+    pub fn build_mesh(&self) -> Mesh {
+        let node_positions = self.shape.node_positions();
+        let positions = self.shape.mesh_positions(&node_positions);
+        let normals = vec![[0., 0., 1.]; positions.len()];
+
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, self.shape.mesh_uvs());
+        mesh.set_indices(Some(Indices::U32(self.shape.triangle_indices())));
+        mesh
+    }
+
+    pub fn spawn(&self, commands: &mut Commands) -> SoftBody {
+        let node_positions = self.shape.node_positions();
+        let root = commands.spawn(TransformBundle::default()).id();
+
+        let fixture_def = b2FixtureDef::new(
+            b2Shape::Circle {
+                radius: self.node_radius,
+                position: Vec2::ZERO,
+            },
+            self.density,
+        );
+        let nodes: Vec<Entity> = node_positions
+            .iter()
+            .map(|position| {
+                let body_def = b2BodyDef {
+                    body_type: b2BodyType::Dynamic,
+                    position: *position,
+                    fixed_rotation: true,
+                    ..default()
+                };
+                let node = commands.spawn(b2BodyBundle::new(&body_def)).id();
+                commands.entity(root).add_child(node);
+
+                let mut fixture = commands.spawn(b2Fixture::new(node, &fixture_def));
+                if let Some(debug_draw) = &self.debug_draw {
+                    fixture.insert(debug_draw.clone());
+                }
+                node
+            })
+            .collect();
+
+        for (a, b) in self.shape.links() {
+            let def = b2DistanceJointDef {
+                length: node_positions[a].distance(node_positions[b]),
+                min_length: 0.,
+                max_length: f32::MAX,
+                stiffness: self.stiffness,
+                damping: self.damping,
+                ..default()
+            };
+            let joint = commands
+                .spawn_empty()
+                .add(CreateDistanceJoint::new(nodes[a], nodes[b], false, &def))
+                .id();
+            commands.entity(root).add_child(joint);
+        }
+
+        let soft_body = SoftBody {
+            root,
+            nodes,
+            shape: self.shape,
+            rest_area: polygon_area(&node_positions),
+            pressure: self.pressure,
+        };
+        commands.entity(root).insert(soft_body.clone());
+        soft_body
+    }
+}
@@ -7,12 +7,17 @@ use std::sync::Arc;
 use autocxx::WithinBox;
 use bevy::prelude::*;
 
-use libliquidfun_sys::box2d::ffi::{b2ContactListenerWrapper, b2RayCastCallbackWrapper, int32};
+use libliquidfun_sys::box2d::ffi::{
+    b2ContactFilterWrapper, b2ContactListenerWrapper, b2QueryCallbackWrapper,
+    b2RayCastCallbackWrapper, int32,
+};
 use libliquidfun_sys::box2d::*;
 
+use crate::collision::{b2AABB, b2MassData};
 use crate::dynamics::{
-    b2Body, b2ContactListener, b2Fixture, b2Joint, b2NoOpFilter, b2RayCast, b2RayCastCallback,
-    b2RayCastFilter, JointPtr,
+    b2Body, b2Contact, b2ContactFilter, b2ContactListener, b2Filter, b2Fixture, b2Joint,
+    b2NoOpFilter, b2PreSolveHook, b2QueryFixtures, b2RayCast, b2RayCastCallback, b2RayCastFilter,
+    b2ShouldCollide, JointPtr,
 };
 use crate::internal::*;
 use crate::particles::{b2ParticleGroup, b2ParticleSystem};
@@ -37,6 +42,27 @@ impl Default for b2WorldSettings {
     }
 }
 
+/// Maps Box2D particle systems back to their entities. Shared by the world with its contact
+/// listener and filter, whose callbacks only receive the Box2D particle system.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct b2ParticleSystemEntities(
+    Rc<RefCell<HashMap<*const ffi::b2ParticleSystem, Entity>>>,
+);
+
+impl b2ParticleSystemEntities {
+    fn insert(&self, particle_system: *const ffi::b2ParticleSystem, entity: Entity) {
+        self.0.borrow_mut().insert(particle_system, entity);
+    }
+
+    pub(crate) fn get(&self, particle_system: &ffi::b2ParticleSystem) -> Option<Entity> {
+        self.0
+            .borrow()
+            .get(&(particle_system as *const ffi::b2ParticleSystem))
+            .copied()
+    }
+}
+
 #[allow(non_camel_case_types)]
 pub struct b2World<'a> {
     ffi_world: Pin<Box<ffi::b2World>>,
@@ -44,7 +70,11 @@ pub struct b2World<'a> {
     body_ptrs: HashMap<Entity, Pin<&'a mut ffi::b2Body>>,
     fixture_ptrs: HashMap<Entity, Pin<&'a mut ffi::b2Fixture>>,
     joint_ptrs: HashMap<Entity, JointPtr<'a>>,
+    joint_to_bodies: HashMap<Entity, (Entity, Entity)>,
+    body_to_joints: HashMap<Entity, HashSet<Entity>>,
+    joint_to_gear_joints: HashMap<Entity, HashSet<Entity>>,
     particle_system_ptrs: HashMap<Entity, Pin<&'a mut ffi::b2ParticleSystem>>,
+    particle_system_entities: b2ParticleSystemEntities,
 
     body_to_fixtures: HashMap<Entity, HashSet<Entity>>,
     fixture_to_body: HashMap<Entity, Entity>,
@@ -53,6 +83,10 @@ pub struct b2World<'a> {
     #[allow(dead_code)]
     ffi_contact_listener: Rc<RefCell<b2ContactListenerWrapper>>,
 
+    contact_filter: Option<Arc<RefCell<b2ShouldCollide>>>,
+    #[allow(dead_code)]
+    ffi_contact_filter: Option<Rc<RefCell<b2ContactFilterWrapper>>>,
+
     pub gravity: Vec2,
 }
 
@@ -62,7 +96,9 @@ impl<'a> b2World<'a> {
     pub fn new(gravity: Vec2) -> Self {
         let ffi_gravity = to_b2Vec2(&gravity);
         let mut ffi_world = ffi::b2World::new(&ffi_gravity).within_box();
-        let contact_listener = b2ContactListener::new();
+        let particle_system_entities = b2ParticleSystemEntities::default();
+        let contact_listener =
+            b2ContactListener::with_particle_systems(particle_system_entities.clone());
         let contact_listener = Arc::new(RefCell::new(contact_listener));
         let ffi_contact_listener = ffi::b2ContactListenerWrapper::new(contact_listener.clone());
 
@@ -81,11 +117,17 @@ impl<'a> b2World<'a> {
             body_ptrs: HashMap::new(),
             fixture_ptrs: HashMap::new(),
             joint_ptrs: HashMap::new(),
+            joint_to_bodies: HashMap::new(),
+            body_to_joints: HashMap::new(),
+            joint_to_gear_joints: HashMap::new(),
             particle_system_ptrs: HashMap::new(),
+            particle_system_entities,
             body_to_fixtures: HashMap::new(),
             fixture_to_body: HashMap::new(),
             contact_listener,
             ffi_contact_listener,
+            contact_filter: None,
+            ffi_contact_filter: None,
         }
     }
 
@@ -118,7 +160,9 @@ impl<'a> b2World<'a> {
         }
     }
 
-    pub(crate) fn destroy_body_for_entity(&mut self, entity: Entity) {
+    /// Destroys the body along with its fixtures and joints. Returns the gear joints that had to be
+    /// destroyed because they referenced one of those joints.
+    pub(crate) fn destroy_body_for_entity(&mut self, entity: Entity) -> Vec<Entity> {
         let body_ptr = self.body_ptrs.remove(&entity).unwrap();
         let fixtures = self.body_to_fixtures.remove(&entity);
         if let Some(fixtures) = fixtures {
@@ -128,10 +172,26 @@ impl<'a> b2World<'a> {
             });
         }
 
+        // DestroyBody also destroys all joints attached to the body, but not the gear joints
+        // referencing them, which would be left with dangling pointers
+        let mut destroyed_gear_joints = Vec::new();
+        let joints = self.body_to_joints.remove(&entity);
+        if let Some(joints) = joints {
+            joints.iter().for_each(|j| {
+                destroyed_gear_joints.append(&mut self.destroy_gear_joints_referencing(j))
+            });
+            joints.iter().for_each(|j| {
+                self.joint_ptrs.remove(j);
+                self.unlink_joint_from_bodies(j);
+            });
+        }
+
         unsafe {
             let body_ptr = Pin::into_inner_unchecked(body_ptr);
             self.ffi_world.as_mut().DestroyBody(body_ptr);
         }
+
+        destroyed_gear_joints
     }
 
     pub(crate) fn create_fixture(
@@ -166,10 +226,90 @@ impl<'a> b2World<'a> {
     pub(crate) fn register_joint(
         &mut self,
         joint: (Entity, &b2Joint, JointPtr<'a>),
-        _body_a: (Entity, &mut b2Body), // TODO
-        _body_b: (Entity, &mut b2Body), // TODO
+        body_a: (Entity, &mut b2Body),
+        body_b: (Entity, &mut b2Body),
     ) {
-        self.joint_ptrs.insert(joint.0, joint.2);
+        let joint_entity = joint.0;
+        self.joint_ptrs.insert(joint_entity, joint.2);
+        self.joint_to_bodies
+            .insert(joint_entity, (body_a.0, body_b.0));
+        self.body_to_joints
+            .entry(body_a.0)
+            .or_default()
+            .insert(joint_entity);
+        self.body_to_joints
+            .entry(body_b.0)
+            .or_default()
+            .insert(joint_entity);
+    }
+
+    /// Destroys the joint. Returns the gear joints that had to be destroyed because they
+    /// referenced it.
+    pub(crate) fn destroy_joint_for_entity(&mut self, entity: Entity) -> Vec<Entity> {
+        let joint_ptr = self.joint_ptrs.remove(&entity);
+
+        // The joint might have already been destroyed on the C++ side through DestroyBody
+        let Some(mut joint_ptr) = joint_ptr else {
+            return Vec::new();
+        };
+
+        self.unlink_joint_from_bodies(&entity);
+        let destroyed_gear_joints = self.destroy_gear_joints_referencing(&entity);
+
+        unsafe {
+            let joint_ptr = joint_ptr.as_ffi_joint();
+            self.ffi_world.as_mut().DestroyJoint(joint_ptr);
+        }
+
+        destroyed_gear_joints
+    }
+
+    pub(crate) fn register_gear_joint(
+        &mut self,
+        gear_joint_entity: Entity,
+        joint_a: Entity,
+        joint_b: Entity,
+    ) {
+        for joint in [joint_a, joint_b] {
+            self.joint_to_gear_joints
+                .entry(joint)
+                .or_default()
+                .insert(gear_joint_entity);
+        }
+    }
+
+    fn destroy_gear_joints_referencing(&mut self, joint_entity: &Entity) -> Vec<Entity> {
+        let Some(gear_joints) = self.joint_to_gear_joints.remove(joint_entity) else {
+            return Vec::new();
+        };
+
+        // a gear joint referencing both joints may already be gone through the other one
+        let gear_joints: Vec<Entity> = gear_joints
+            .into_iter()
+            .filter(|g| self.joint_ptrs.contains_key(g))
+            .collect();
+        gear_joints.iter().for_each(|g| {
+            self.destroy_joint_for_entity(*g);
+        });
+        gear_joints
+    }
+
+    fn unlink_joint_from_bodies(&mut self, joint_entity: &Entity) {
+        let Some((body_a, body_b)) = self.joint_to_bodies.remove(joint_entity) else {
+            return;
+        };
+        for body in [body_a, body_b] {
+            if let Some(joints) = self.body_to_joints.get_mut(&body) {
+                joints.remove(joint_entity);
+            }
+        }
+    }
+
+    pub(crate) fn get_joints_attached_to_entity(
+        &self,
+        body_entity: &Entity,
+    ) -> Option<&HashSet<Entity>> {
+        self.body_to_joints.get(body_entity)
     }
     pub(crate) fn destroy_fixture_for_entity(&mut self, entity: Entity) {
         let fixture_ptr = self.fixture_ptrs.remove(&entity);
@@ -212,6 +352,11 @@ impl<'a> b2World<'a> {
             ffi_particle_system
                 .as_mut()
                 .SetPositionBuffer(positions.as_mut_ptr() as *mut ffi::b2Vec2, capacity);
+
+            let particle_system_ptr = ffi_particle_system.as_ref().get_ref() as *const _;
+            self.particle_system_entities
+                .insert(particle_system_ptr, entity);
+
             self.particle_system_ptrs
                 .insert(entity, ffi_particle_system);
         }
@@ -243,7 +388,8 @@ impl<'a> b2World<'a> {
             ffi::int32::from(velocity_iterations),
             ffi::int32::from(position_iterations),
             ffi::int32::from(particle_iterations),
-        )
+        );
+        self.contact_listener.borrow_mut().advance_step();
     }
 
     pub(crate) fn get_fixtures_attached_to_entity(
@@ -253,6 +399,95 @@ impl<'a> b2World<'a> {
         self.body_to_fixtures.get(body_entity)
     }
 
+    pub(crate) fn set_fixture_filter(&mut self, fixture_entity: &Entity, filter: &b2Filter) {
+        if let Some(fixture_ptr) = self.fixture_ptrs.get_mut(fixture_entity) {
+            fixture_ptr.as_mut().SetFilterData(&filter.to_ffi());
+        }
+    }
+
+    /// Returns the world-space AABB of the fixture at its body's current transform, combined over
+    /// all of its child shapes (chains have one child per edge). Returns `None` if the body is
+    /// disabled.
+    pub fn fixture_aabb(&self, fixture_entity: &Entity) -> Option<b2AABB> {
+        let fixture_ptr = self.fixture_ptrs.get(fixture_entity)?;
+        let body_entity = self.fixture_to_body.get(fixture_entity)?;
+        let body_ptr = self.body_ptrs.get(body_entity)?;
+        if !body_ptr.as_ref().IsEnabled() {
+            return None;
+        }
+
+        let transform = body_ptr.as_ref().GetTransform();
+        let shape = unsafe { fixture_ptr.as_ref().GetShape1().as_ref().unwrap() };
+        let child_count = i32::from(shape.GetChildCount());
+        (0..child_count)
+            .map(|child_index| {
+                let mut aabb = b2AABB::default().to_ffi();
+                unsafe {
+                    shape.ComputeAABB(&mut aabb, transform, int32::from(child_index));
+                }
+                b2AABB::from_ffi(&aabb)
+            })
+            .reduce(|a, b| a.combine(&b))
+    }
+
+    /// Returns the mass data computed from the fixture's shape and density.
+    pub fn fixture_mass_data(&self, fixture_entity: &Entity) -> Option<b2MassData> {
+        let fixture_ptr = self.fixture_ptrs.get(fixture_entity)?;
+        let mut mass_data = ffi::b2MassData::new().within_box();
+        unsafe {
+            let mass_data_ptr = mass_data.as_mut().get_unchecked_mut() as *mut ffi::b2MassData;
+            fixture_ptr.as_ref().GetMassData(mass_data_ptr);
+        }
+        Some(b2MassData::from_ffi(&mass_data))
+    }
+
+    /// Tests whether a world-space point lies inside the fixture. Always false for edge and
+    /// chain shapes, since they have no area.
+    pub fn fixture_test_point(&self, fixture_entity: &Entity, point: &Vec2) -> bool {
+        self.fixture_ptrs
+            .get(fixture_entity)
+            .map_or(false, |fixture_ptr| {
+                fixture_ptr.as_ref().TestPoint(&to_b2Vec2(point))
+            })
+    }
+
+    /// The fixtures whose AABB overlaps the given AABB, in no particular order. The fixtures
+    /// themselves don't necessarily overlap it.
+    pub fn query_aabb(&mut self, aabb: &b2AABB) -> Vec<Entity> {
+        let query = Arc::new(RefCell::new(b2QueryFixtures::default()));
+        let query_callback_wrapper = b2QueryCallbackWrapper::new(query.clone());
+        unsafe {
+            let ffi_callback: *mut ffi::b2QueryCallback = query_callback_wrapper
+                .as_ref()
+                .borrow_mut()
+                .pin_mut()
+                .as_mut()
+                .get_unchecked_mut();
+            self.ffi_world
+                .as_mut()
+                .QueryAABB(ffi_callback, &aabb.to_ffi());
+        }
+        Arc::try_unwrap(query).unwrap().into_inner().extract_hits()
+    }
+
+    /// The bodies with a fixture containing the given world-space point, in no particular order.
+    pub fn query_point(&mut self, point: &Vec2) -> Vec<Entity> {
+        let aabb = b2AABB {
+            lower_bound: *point - Vec2::splat(0.001),
+            upper_bound: *point + Vec2::splat(0.001),
+        };
+        let mut bodies = Vec::new();
+        for fixture in self.query_aabb(&aabb) {
+            let Some(body) = self.fixture_to_body.get(&fixture).copied() else {
+                continue;
+            };
+            if self.fixture_test_point(&fixture, point) && !bodies.contains(&body) {
+                bodies.push(body);
+            }
+        }
+        bodies
+    }
+
     pub(crate) fn get_particle_system_ptr(
         &self,
         particle_system_entity: &Entity,
@@ -275,6 +510,79 @@ impl<'a> b2World<'a> {
         self.contact_listener.clone()
     }
 
+    /// Re-reads the manifold and solver state of every touching contact from Box2D.
+    pub(crate) fn refresh_contacts(&mut self) {
+        let mut contact_listener = self.contact_listener.borrow_mut();
+        unsafe {
+            let mut contact_ptr = self.ffi_world.as_mut().GetContactList();
+            while let Some(contact) = contact_ptr.as_mut() {
+                if Pin::new_unchecked(&mut *contact).as_ref().IsTouching() {
+                    contact_listener.refresh_fixture_contact(b2Contact::from_ffi_contact(contact));
+                }
+                contact_ptr = Pin::new_unchecked(contact).GetNext();
+            }
+        }
+    }
+
+    /// The current state of every touching contact, as of the end of the last physics step.
+    pub fn touching_contacts(&self) -> Vec<b2Contact> {
+        self.contact_listener
+            .borrow()
+            .fixture_contacts()
+            .values()
+            .copied()
+            .collect()
+    }
+
+    /// The current state of every touching contact involving the given body.
+    pub fn touching_contacts_for_body(&self, body_entity: Entity) -> Vec<b2Contact> {
+        self.contact_listener
+            .borrow()
+            .fixture_contacts()
+            .values()
+            .filter(|contact| contact.body_a == body_entity || contact.body_b == body_entity)
+            .copied()
+            .collect()
+    }
+
+    /// Installs a contact filter that can veto collisions between fixtures and particles,
+    /// replacing any previously installed one. The category, mask and group rules of each
+    /// fixture's [`b2Filter`](crate::dynamics::b2Filter) are still applied first. Existing contacts
+    /// are filtered again during the next step.
+    pub fn set_contact_filter<T: b2ContactFilter + 'static>(&mut self, filter: T) {
+        let contact_filter =
+            b2ShouldCollide::new(Box::new(filter), self.particle_system_entities.clone());
+        let contact_filter = Arc::new(RefCell::new(contact_filter));
+        let ffi_contact_filter = b2ContactFilterWrapper::new(contact_filter.clone());
+
+        unsafe {
+            let ffi_contact_filter: *mut ffi::b2ContactFilter = ffi_contact_filter
+                .as_ref()
+                .borrow_mut()
+                .pin_mut()
+                .as_mut()
+                .get_unchecked_mut();
+            self.ffi_world.as_mut().SetContactFilter(ffi_contact_filter);
+        }
+
+        self.contact_filter = Some(contact_filter);
+        self.ffi_contact_filter = Some(ffi_contact_filter);
+
+        for fixture_ptr in self.fixture_ptrs.values_mut() {
+            fixture_ptr.as_mut().Refilter();
+        }
+    }
+
+    /// Registers a hook that can modify or disable contacts right before they are solved.
+    /// Hooks run in registration order, after the built-in [`OneWayPlatform`] handling.
+    ///
+    /// [`OneWayPlatform`]: crate::dynamics::OneWayPlatform
+    pub fn add_pre_solve_hook<T: b2PreSolveHook + 'static>(&mut self, hook: T) {
+        self.contact_listener
+            .borrow_mut()
+            .add_pre_solve_hook(Box::new(hook));
+    }
+
     pub fn ray_cast<T: b2RayCastCallback + 'static>(
         &mut self,
         callback: T,
@@ -9,6 +9,9 @@ pub mod dynamics {
     mod joints {
         mod joint;
         pub use joint::*;
+
+        mod breakable;
+        pub use breakable::*;
         mod revolute_joint;
         pub use revolute_joint::*;
 
@@ -20,24 +23,58 @@ pub mod dynamics {
 
         mod mouse_joint;
         pub use mouse_joint::*;
+
+        mod weld_joint;
+        pub use weld_joint::*;
+
+        mod wheel_joint;
+        pub use wheel_joint::*;
+
+        mod motor_joint;
+        pub use motor_joint::*;
+
+        mod friction_joint;
+        pub use friction_joint::*;
+
+        mod pulley_joint;
+        pub use pulley_joint::*;
+
+        mod gear_joint;
+        pub use gear_joint::*;
     }
     mod contacts {
+        mod colliding_entities;
+        pub use colliding_entities::*;
+        mod contact_filter;
+        pub use contact_filter::*;
         mod contact_events;
         pub use contact_events::*;
         mod contact_listener;
         pub use contact_listener::*;
         mod contacts;
         pub use contacts::*;
+        mod one_way_platform;
+        pub use one_way_platform::*;
+        mod pre_solve;
+        pub use pre_solve::*;
     }
+    mod collision_layers;
     mod fixture;
+    mod query;
     mod ray_cast;
+    mod rope;
+    mod soft_body;
     mod world;
 
     pub use body::*;
+    pub use collision_layers::*;
     pub use contacts::*;
     pub use fixture::*;
     pub use joints::*;
+    pub use query::*;
     pub use ray_cast::*;
+    pub use rope::*;
+    pub use soft_body::*;
     pub use world::*;
 }
 
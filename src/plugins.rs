@@ -1,13 +1,25 @@
+use std::f32::consts::PI;
+
 use bevy::prelude::*;
+use bevy::sprite::Mesh2dHandle;
 use bevy::transform::TransformSystem;
+use bevy::utils::{HashMap, HashSet};
+use bevy::window::PrimaryWindow;
 
 use libliquidfun_sys::box2d::ffi::int32;
 
 use crate::collision::b2Shape;
 use crate::dynamics::{
-    b2BeginContactEvent, b2Body, b2DistanceJoint, b2EndContactEvent, b2Fixture, b2Joint,
-    b2MouseJoint, b2ParticleBodyContact, b2ParticleContacts, b2PrismaticJoint, b2RevoluteJoint,
-    b2World, b2WorldSettings, ExternalForce, ExternalTorque, GravityScale, JointPtr,
+    b2BeginContactEvent, b2Body, b2BodyBundle, b2BodyParticleContact, b2BodyType,
+    b2ContactEventKind, b2ContactImpulseEvent, b2ContactStepEvent, b2DistanceJoint,
+    b2EndContactEvent, b2Fixture, b2FrictionJoint, b2GearJoint, b2Joint, b2MotorJoint,
+    b2MouseJoint, b2MouseJointDef, b2ParticleBodyBeginEvent, b2ParticleBodyContact,
+    b2ParticleBodyEndEvent, b2ParticleContact, b2ParticleContacts, b2ParticleParticleBeginEvent,
+    b2ParticleParticleEndEvent, b2PrismaticJoint, b2PulleyJoint, b2RevoluteJoint, b2WeldJoint,
+    b2WheelJoint, b2World, b2WorldSettings, Breakable, CollidingEntities, CollisionLayerMatrix,
+    CollisionLayers, ContactCallbacks, ContactImpulseThreshold, CreateMouseJoint, ExternalForce,
+    ExternalTorque, FluidInteraction, GravityScale, JointBrokeEvent, JointPtr, JointState,
+    OneWayPlatform, SensorEnterEvent, SensorExitEvent, SensorFilter, SoftBody,
 };
 use crate::internal::to_b2Vec2;
 use crate::particles::{b2ParticleGroup, b2ParticleSystem, b2ParticleSystemContacts};
@@ -52,75 +64,183 @@ impl Plugin for LiquidFunPlugin {
         .add_systems(
             PostStartup,
             (
-                create_bodies,
-                create_fixtures,
-                create_revolute_joints,
-                create_prismatic_joints,
-                create_distance_joints,
-                create_mouse_joints,
-                create_particle_systems,
-                create_particle_groups,
-                create_queued_particles,
-                destroy_removed_fixtures,
-                destroy_removed_bodies,
-                destroy_queued_particles,
-                apply_deferred,
-                sync_bodies_to_world,
-                sync_revolute_joints_to_world,
-                sync_prismatic_joints_to_world,
-                sync_distance_joints_to_world,
-                sync_mouse_joints_to_world,
-            )
-                .chain(),
-        )
-        .add_systems(
-            PostUpdate,
-            (
-                (
-                    clear_events::<b2BeginContactEvent>,
-                    clear_events::<b2EndContactEvent>,
-                )
-                    .in_set(LiquidFunSet::ClearEvents),
                 (
+                    apply_collision_layers,
                     create_bodies,
                     create_fixtures,
                     create_revolute_joints,
                     create_prismatic_joints,
                     create_distance_joints,
                     create_mouse_joints,
+                    create_weld_joints,
+                    create_wheel_joints,
+                    create_motor_joints,
+                    create_friction_joints,
+                    create_pulley_joints,
+                    create_gear_joints,
+                )
+                    .chain(),
+                (
                     create_particle_systems,
                     create_particle_groups,
                     create_queued_particles,
+                )
+                    .chain(),
+                (
+                    destroy_removed_joints::<b2Joint>,
+                    destroy_removed_joints::<b2RevoluteJoint>,
+                    destroy_removed_joints::<b2PrismaticJoint>,
+                    destroy_removed_joints::<b2DistanceJoint>,
+                    destroy_removed_joints::<b2MouseJoint>,
+                    destroy_removed_joints::<b2WeldJoint>,
+                    destroy_removed_joints::<b2WheelJoint>,
+                    destroy_removed_joints::<b2MotorJoint>,
+                    destroy_removed_joints::<b2FrictionJoint>,
+                    destroy_removed_joints::<b2PulleyJoint>,
+                    destroy_removed_joints::<b2GearJoint>,
                     destroy_removed_fixtures,
                     destroy_removed_bodies,
                     destroy_queued_particles,
-                    apply_deferred,
+                )
+                    .chain(),
+                apply_deferred,
+                (
                     sync_bodies_to_world,
+                    sync_fixtures_to_world,
                     sync_revolute_joints_to_world,
                     sync_prismatic_joints_to_world,
                     sync_distance_joints_to_world,
                     sync_mouse_joints_to_world,
+                    sync_weld_joints_to_world,
+                    sync_wheel_joints_to_world,
+                    sync_motor_joints_to_world,
+                    sync_friction_joints_to_world,
+                    sync_gear_joints_to_world,
+                    sync_one_way_platforms_to_world,
+                    sync_contact_impulse_thresholds_to_world,
+                )
+                    .chain(),
+            )
+                .chain(),
+        )
+        .add_systems(
+            PostUpdate,
+            (
+                (
+                    clear_events::<b2BeginContactEvent>,
+                    clear_events::<b2EndContactEvent>,
+                    clear_events::<b2ContactImpulseEvent>,
+                    clear_events::<b2ContactStepEvent>,
+                    clear_events::<b2ParticleBodyBeginEvent>,
+                    clear_events::<b2ParticleBodyEndEvent>,
+                    clear_events::<b2ParticleParticleBeginEvent>,
+                    clear_events::<b2ParticleParticleEndEvent>,
+                    clear_events::<SensorEnterEvent>,
+                    clear_events::<SensorExitEvent>,
+                    clear_events::<JointBrokeEvent>,
+                )
+                    .in_set(LiquidFunSet::ClearEvents),
+                (
+                    (
+                        apply_collision_layers,
+                        create_bodies,
+                        create_fixtures,
+                        create_revolute_joints,
+                        create_prismatic_joints,
+                        create_distance_joints,
+                        create_mouse_joints,
+                        create_weld_joints,
+                        create_wheel_joints,
+                        create_motor_joints,
+                        create_friction_joints,
+                        create_pulley_joints,
+                        create_gear_joints,
+                    )
+                        .chain(),
+                    (
+                        create_particle_systems,
+                        create_particle_groups,
+                        create_queued_particles,
+                    )
+                        .chain(),
+                    (
+                        destroy_removed_joints::<b2Joint>,
+                        destroy_removed_joints::<b2RevoluteJoint>,
+                        destroy_removed_joints::<b2PrismaticJoint>,
+                        destroy_removed_joints::<b2DistanceJoint>,
+                        destroy_removed_joints::<b2MouseJoint>,
+                        destroy_removed_joints::<b2WeldJoint>,
+                        destroy_removed_joints::<b2WheelJoint>,
+                        destroy_removed_joints::<b2MotorJoint>,
+                        destroy_removed_joints::<b2FrictionJoint>,
+                        destroy_removed_joints::<b2PulleyJoint>,
+                        destroy_removed_joints::<b2GearJoint>,
+                        destroy_removed_fixtures,
+                        destroy_removed_bodies,
+                        destroy_queued_particles,
+                    )
+                        .chain(),
+                    apply_deferred,
+                    (
+                        sync_bodies_to_world,
+                        sync_fixtures_to_world,
+                        sync_revolute_joints_to_world,
+                        sync_prismatic_joints_to_world,
+                        sync_distance_joints_to_world,
+                        sync_mouse_joints_to_world,
+                        sync_weld_joints_to_world,
+                        sync_wheel_joints_to_world,
+                        sync_motor_joints_to_world,
+                        sync_friction_joints_to_world,
+                        sync_gear_joints_to_world,
+                        sync_one_way_platforms_to_world,
+                        sync_contact_impulse_thresholds_to_world,
+                    )
+                        .chain(),
                 )
                     .chain()
                     .in_set(LiquidFunSet::SyncToPhysicsWorld),
-                (apply_forces, apply_torques, apply_gravity_scale)
+                (
+                    apply_forces,
+                    apply_torques,
+                    apply_gravity_scale,
+                    apply_fluid_interaction,
+                    apply_soft_body_pressure,
+                )
                     .chain()
                     .in_set(LiquidFunSet::ApplyForces),
                 (step_physics).in_set(LiquidFunSet::Step),
                 (
                     sync_bodies_from_world,
+                    sync_joint_states_from_world,
                     sync_particle_systems_from_world,
                     update_transforms,
                     send_contact_events,
+                    refresh_contacts,
+                    update_colliding_entities,
+                    run_contact_callbacks,
+                    send_sensor_events,
+                    send_particle_contact_events,
                     copy_particle_system_contacts,
                     update_particle_body_contacts_components,
+                    update_soft_body_meshes,
                 )
                     .chain()
                     .in_set(LiquidFunSet::SyncFromPhysicsWorld),
             ),
         )
+        .init_resource::<CollisionLayerMatrix>()
         .init_resource::<Events<b2BeginContactEvent>>()
-        .init_resource::<Events<b2EndContactEvent>>();
+        .init_resource::<Events<b2EndContactEvent>>()
+        .init_resource::<Events<b2ContactImpulseEvent>>()
+        .init_resource::<Events<b2ContactStepEvent>>()
+        .init_resource::<Events<b2ParticleBodyBeginEvent>>()
+        .init_resource::<Events<b2ParticleBodyEndEvent>>()
+        .init_resource::<Events<b2ParticleParticleBeginEvent>>()
+        .init_resource::<Events<b2ParticleParticleEndEvent>>()
+        .init_resource::<Events<SensorEnterEvent>>()
+        .init_resource::<Events<SensorExitEvent>>()
+        .init_resource::<Events<JointBrokeEvent>>();
     }
 }
 
@@ -132,6 +252,10 @@ fn step_physics(
     settings: Res<b2WorldSettings>,
     time: Res<Time>,
     mut physics_time_accumulator: ResMut<PhysicsTimeAccumulator>,
+    mut commands: Commands,
+    breakable_joints: Query<(Entity, &Breakable)>,
+    joints: Query<&b2Joint>,
+    mut joint_broke_events: EventWriter<JointBrokeEvent>,
 ) {
     physics_time_accumulator.0 += time.delta_seconds();
 
@@ -143,6 +267,18 @@ fn step_physics(
             settings.particle_iterations,
         );
         physics_time_accumulator.0 -= settings.time_step;
+
+        // reaction forces only reflect the last step, so overloads have to be caught right away
+        if !breakable_joints.is_empty() {
+            break_joints(
+                &mut commands,
+                &mut b2_world,
+                1. / settings.time_step,
+                &breakable_joints,
+                &joints,
+                &mut joint_broke_events,
+            );
+        }
     }
 }
 
@@ -162,6 +298,20 @@ fn clear_events<T: 'static + Send + Sync + Event>(mut events: ResMut<Events<T>>)
     events.clear();
 }
 
+fn apply_collision_layers(
+    layer_matrix: Res<CollisionLayerMatrix>,
+    mut fixtures: Query<(Ref<CollisionLayers>, &mut b2Fixture)>,
+) {
+    for (layers, mut fixture) in &mut fixtures {
+        if !layer_matrix.is_changed() && !layers.is_changed() {
+            continue;
+        }
+
+        let filter = layers.to_filter(&layer_matrix, fixture.def().filter.group_index);
+        fixture.set_filter(filter);
+    }
+}
+
 fn create_bodies(
     mut b2_world: NonSendMut<b2World>,
     mut added: Query<(Entity, &mut b2Body), Added<b2Body>>,
@@ -271,7 +421,149 @@ fn create_mouse_joints(
             (body_a.0, &mut body_a.1),
             (body_b.0, &mut body_b.1),
         );
-        println!("Mouse joint registered");
+    }
+}
+
+fn create_weld_joints(
+    mut b2_world: NonSendMut<b2World>,
+    mut added: Query<(Entity, &b2Joint, &b2WeldJoint), Added<b2WeldJoint>>,
+    mut bodies: Query<(Entity, &mut b2Body)>,
+) {
+    for (joint_entity, joint, weld_joint) in added.iter_mut() {
+        let [mut body_a, mut body_b] = bodies
+            .get_many_mut([*joint.body_a(), *joint.body_b()])
+            .unwrap();
+        let joint_ptr = weld_joint.create_ffi_joint(
+            &mut b2_world,
+            body_a.0,
+            body_b.0,
+            joint.collide_connected(),
+        );
+        b2_world.register_joint(
+            (joint_entity, &joint, joint_ptr),
+            (body_a.0, &mut body_a.1),
+            (body_b.0, &mut body_b.1),
+        );
+    }
+}
+
+fn create_wheel_joints(
+    mut b2_world: NonSendMut<b2World>,
+    mut added: Query<(Entity, &b2Joint, &b2WheelJoint), Added<b2WheelJoint>>,
+    mut bodies: Query<(Entity, &mut b2Body)>,
+) {
+    for (joint_entity, joint, wheel_joint) in added.iter_mut() {
+        let [mut body_a, mut body_b] = bodies
+            .get_many_mut([*joint.body_a(), *joint.body_b()])
+            .unwrap();
+        let joint_ptr = wheel_joint.create_ffi_joint(
+            &mut b2_world,
+            body_a.0,
+            body_b.0,
+            joint.collide_connected(),
+        );
+        b2_world.register_joint(
+            (joint_entity, &joint, joint_ptr),
+            (body_a.0, &mut body_a.1),
+            (body_b.0, &mut body_b.1),
+        );
+    }
+}
+
+fn create_motor_joints(
+    mut b2_world: NonSendMut<b2World>,
+    mut added: Query<(Entity, &b2Joint, &b2MotorJoint), Added<b2MotorJoint>>,
+    mut bodies: Query<(Entity, &mut b2Body)>,
+) {
+    for (joint_entity, joint, motor_joint) in added.iter_mut() {
+        let [mut body_a, mut body_b] = bodies
+            .get_many_mut([*joint.body_a(), *joint.body_b()])
+            .unwrap();
+        let joint_ptr = motor_joint.create_ffi_joint(
+            &mut b2_world,
+            body_a.0,
+            body_b.0,
+            joint.collide_connected(),
+        );
+        b2_world.register_joint(
+            (joint_entity, &joint, joint_ptr),
+            (body_a.0, &mut body_a.1),
+            (body_b.0, &mut body_b.1),
+        );
+    }
+}
+
+fn create_friction_joints(
+    mut b2_world: NonSendMut<b2World>,
+    mut added: Query<(Entity, &b2Joint, &b2FrictionJoint), Added<b2FrictionJoint>>,
+    mut bodies: Query<(Entity, &mut b2Body)>,
+) {
+    for (joint_entity, joint, friction_joint) in added.iter_mut() {
+        let [mut body_a, mut body_b] = bodies
+            .get_many_mut([*joint.body_a(), *joint.body_b()])
+            .unwrap();
+        let joint_ptr = friction_joint.create_ffi_joint(
+            &mut b2_world,
+            body_a.0,
+            body_b.0,
+            joint.collide_connected(),
+        );
+        b2_world.register_joint(
+            (joint_entity, &joint, joint_ptr),
+            (body_a.0, &mut body_a.1),
+            (body_b.0, &mut body_b.1),
+        );
+    }
+}
+
+fn create_pulley_joints(
+    mut b2_world: NonSendMut<b2World>,
+    mut added: Query<(Entity, &b2Joint, &b2PulleyJoint), Added<b2PulleyJoint>>,
+    mut bodies: Query<(Entity, &mut b2Body)>,
+) {
+    for (joint_entity, joint, pulley_joint) in added.iter_mut() {
+        let [mut body_a, mut body_b] = bodies
+            .get_many_mut([*joint.body_a(), *joint.body_b()])
+            .unwrap();
+        let joint_ptr = pulley_joint.create_ffi_joint(
+            &mut b2_world,
+            body_a.0,
+            body_b.0,
+            joint.collide_connected(),
+        );
+        b2_world.register_joint(
+            (joint_entity, &joint, joint_ptr),
+            (body_a.0, &mut body_a.1),
+            (body_b.0, &mut body_b.1),
+        );
+    }
+}
+
+/// Runs after all other joint creation systems, since gear joints need the joints they
+/// reference to exist in the physics world.
+fn create_gear_joints(
+    mut b2_world: NonSendMut<b2World>,
+    mut added: Query<(Entity, &b2Joint, &b2GearJoint), Added<b2GearJoint>>,
+    mut bodies: Query<(Entity, &mut b2Body)>,
+) {
+    for (joint_entity, joint, gear_joint) in added.iter_mut() {
+        let [mut body_a, mut body_b] = bodies
+            .get_many_mut([*joint.body_a(), *joint.body_b()])
+            .unwrap();
+        let Some(joint_ptr) = gear_joint.create_ffi_joint(
+            &mut b2_world,
+            body_a.0,
+            body_b.0,
+            joint.collide_connected(),
+        ) else {
+            continue;
+        };
+        b2_world.register_joint(
+            (joint_entity, &joint, joint_ptr),
+            (body_a.0, &mut body_a.1),
+            (body_b.0, &mut body_b.1),
+        );
+        b2_world.register_gear_joint(joint_entity, gear_joint.joint_a(), gear_joint.joint_b());
     }
 }
 
@@ -324,7 +616,17 @@ fn destroy_removed_bodies(
             });
         }
 
-        b2_world.destroy_body_for_entity(entity);
+        let joint_entities = b2_world.get_joints_attached_to_entity(&entity);
+        if let Some(joint_entities) = joint_entities {
+            joint_entities.iter().for_each(|joint_entity| {
+                if let Some(mut joint_entity) = commands.get_entity(*joint_entity) {
+                    joint_entity.despawn_recursive();
+                }
+            });
+        }
+
+        let gear_joint_entities = b2_world.destroy_body_for_entity(entity);
+        despawn_destroyed_gear_joints(&mut commands, &gear_joint_entities);
     }
 }
 
@@ -338,6 +640,26 @@ fn destroy_queued_particles(
     }
 }
 
+/// Destroys the joint as soon as either its `b2Joint` or its typed joint component is removed.
+fn destroy_removed_joints<T: Component>(
+    mut b2_world: NonSendMut<b2World>,
+    mut removed: RemovedComponents<T>,
+    mut commands: Commands,
+) {
+    for entity in removed.read() {
+        let gear_joint_entities = b2_world.destroy_joint_for_entity(entity);
+        despawn_destroyed_gear_joints(&mut commands, &gear_joint_entities);
+    }
+}
+
+fn despawn_destroyed_gear_joints(commands: &mut Commands, gear_joint_entities: &[Entity]) {
+    gear_joint_entities.iter().for_each(|gear_joint_entity| {
+        if let Some(mut gear_joint_entity) = commands.get_entity(*gear_joint_entity) {
+            gear_joint_entity.despawn_recursive();
+        }
+    });
+}
+
 fn destroy_removed_fixtures(
     mut b2_world: NonSendMut<b2World>,
     mut removed: RemovedComponents<b2Fixture>,
@@ -355,15 +677,28 @@ fn sync_bodies_to_world(
     }
 }
 
+fn sync_fixtures_to_world(
+    mut b2_world: NonSendMut<b2World>,
+    fixtures: Query<(Entity, Ref<b2Fixture>), Changed<b2Fixture>>,
+) {
+    for (entity, fixture) in fixtures.iter() {
+        // freshly added fixtures were just created with their current definition
+        if fixture.is_added() {
+            continue;
+        }
+        b2_world.set_fixture_filter(&entity, &fixture.def().filter);
+    }
+}
+
 fn sync_revolute_joints_to_world(
     mut b2_world: NonSendMut<b2World>,
     joints: Query<(Entity, &b2RevoluteJoint), Changed<b2RevoluteJoint>>,
 ) {
     for (entity, joint) in joints.iter() {
-        let joint_ptr = b2_world.get_joint_ptr(&entity).unwrap();
-        if let JointPtr::Revolute(joint_ptr) = joint_ptr {
-            joint.sync_to_world(joint_ptr.as_mut());
-        }
+        let Some(JointPtr::Revolute(joint_ptr)) = b2_world.get_joint_ptr(&entity) else {
+            continue;
+        };
+        joint.sync_to_world(joint_ptr.as_mut());
     }
 }
 
@@ -372,10 +707,10 @@ fn sync_prismatic_joints_to_world(
     joints: Query<(Entity, &b2PrismaticJoint), Changed<b2PrismaticJoint>>,
 ) {
     for (entity, joint) in joints.iter() {
-        let joint_ptr = b2_world.get_joint_ptr(&entity).unwrap();
-        if let JointPtr::Prismatic(joint_ptr) = joint_ptr {
-            joint.sync_to_world(joint_ptr.as_mut());
-        }
+        let Some(JointPtr::Prismatic(joint_ptr)) = b2_world.get_joint_ptr(&entity) else {
+            continue;
+        };
+        joint.sync_to_world(joint_ptr.as_mut());
     }
 }
 
@@ -384,10 +719,10 @@ fn sync_distance_joints_to_world(
     joints: Query<(Entity, &b2DistanceJoint), Changed<b2DistanceJoint>>,
 ) {
     for (entity, joint) in joints.iter() {
-        let joint_ptr = b2_world.get_joint_ptr(&entity).unwrap();
-        if let JointPtr::Distance(joint_ptr) = joint_ptr {
-            joint.sync_to_world(joint_ptr.as_mut());
-        }
+        let Some(JointPtr::Distance(joint_ptr)) = b2_world.get_joint_ptr(&entity) else {
+            continue;
+        };
+        joint.sync_to_world(joint_ptr.as_mut());
     }
 }
 
@@ -396,13 +731,105 @@ fn sync_mouse_joints_to_world(
     joints: Query<(Entity, &b2MouseJoint), Changed<b2MouseJoint>>,
 ) {
     for (entity, joint) in joints.iter() {
-        let joint_ptr = b2_world.get_joint_ptr(&entity).unwrap();
-        if let JointPtr::Mouse(joint_ptr) = joint_ptr {
+        let Some(JointPtr::Mouse(joint_ptr)) = b2_world.get_joint_ptr(&entity) else {
+            continue;
+        };
+        joint.sync_to_world(joint_ptr.as_mut());
+    }
+}
+
+fn sync_weld_joints_to_world(
+    mut b2_world: NonSendMut<b2World>,
+    joints: Query<(Entity, &b2WeldJoint), Changed<b2WeldJoint>>,
+) {
+    for (entity, joint) in joints.iter() {
+        let Some(JointPtr::Weld(joint_ptr)) = b2_world.get_joint_ptr(&entity) else {
+            continue;
+        };
+        joint.sync_to_world(joint_ptr.as_mut());
+    }
+}
+
+fn sync_wheel_joints_to_world(
+    mut b2_world: NonSendMut<b2World>,
+    joints: Query<(Entity, &b2WheelJoint), Changed<b2WheelJoint>>,
+) {
+    for (entity, joint) in joints.iter() {
+        let Some(JointPtr::Wheel(joint_ptr)) = b2_world.get_joint_ptr(&entity) else {
+            continue;
+        };
+        joint.sync_to_world(joint_ptr.as_mut());
+    }
+}
+
+fn sync_motor_joints_to_world(
+    mut b2_world: NonSendMut<b2World>,
+    joints: Query<(Entity, &b2MotorJoint), Changed<b2MotorJoint>>,
+) {
+    for (entity, joint) in joints.iter() {
+        let Some(JointPtr::Motor(joint_ptr)) = b2_world.get_joint_ptr(&entity) else {
+            continue;
+        };
+        joint.sync_to_world(joint_ptr.as_mut());
+    }
+}
+
+fn sync_friction_joints_to_world(
+    mut b2_world: NonSendMut<b2World>,
+    joints: Query<(Entity, &b2FrictionJoint), Changed<b2FrictionJoint>>,
+) {
+    for (entity, joint) in joints.iter() {
+        let Some(JointPtr::Friction(joint_ptr)) = b2_world.get_joint_ptr(&entity) else {
+            continue;
+        };
+        joint.sync_to_world(joint_ptr.as_mut());
+    }
+}
+
+fn sync_gear_joints_to_world(
+    mut b2_world: NonSendMut<b2World>,
+    joints: Query<(Entity, &b2GearJoint), Changed<b2GearJoint>>,
+) {
+    for (entity, joint) in joints.iter() {
+        // the gear joint is gone once one of the joints it references has been destroyed
+        if let Some(JointPtr::Gear(joint_ptr)) = b2_world.get_joint_ptr(&entity) {
             joint.sync_to_world(joint_ptr.as_mut());
         }
     }
 }
 
+fn sync_one_way_platforms_to_world(
+    b2_world: NonSendMut<b2World>,
+    platforms: Query<(Entity, &OneWayPlatform), Changed<OneWayPlatform>>,
+    mut removed: RemovedComponents<OneWayPlatform>,
+) {
+    let contact_listener = b2_world.contact_listener();
+    let mut contact_listener = contact_listener.borrow_mut();
+    for entity in removed.read() {
+        contact_listener.remove_one_way_platform(entity);
+    }
+
+    for (entity, platform) in platforms.iter() {
+        contact_listener.set_one_way_platform(entity, *platform);
+    }
+}
+
+fn sync_contact_impulse_thresholds_to_world(
+    b2_world: NonSendMut<b2World>,
+    thresholds: Query<(Entity, &ContactImpulseThreshold), Changed<ContactImpulseThreshold>>,
+    mut removed: RemovedComponents<ContactImpulseThreshold>,
+) {
+    let contact_listener = b2_world.contact_listener();
+    let mut contact_listener = contact_listener.borrow_mut();
+    for entity in removed.read() {
+        contact_listener.remove_contact_impulse_threshold(entity);
+    }
+
+    for (entity, threshold) in thresholds.iter() {
+        contact_listener.set_contact_impulse_threshold(entity, threshold.0);
+    }
+}
+
 fn apply_forces(
     mut b2_world: NonSendMut<b2World>,
     external_forces: Query<(Entity, &ExternalForce)>,
@@ -461,12 +888,136 @@ fn apply_gravity_scale(
         }
     }
 }
+
+fn apply_fluid_interaction(
+    mut b2_world: NonSendMut<b2World>,
+    bodies: Query<(Entity, &b2Body, &FluidInteraction, &b2ParticleContacts)>,
+    particle_systems: Query<&b2ParticleSystem>,
+) {
+    let gravity = b2_world.gravity;
+    for (entity, body, fluid_interaction, particle_contacts) in bodies.iter() {
+        let contacts = particle_contacts.contacts();
+        if contacts.is_empty() {
+            continue;
+        }
+
+        let Some(body_ptr) = b2_world.get_body_ptr_mut(entity) else {
+            warn!(
+                "Encountered FluidInteraction component on an Entity without a matching b2Body: {:?}",
+                entity
+            );
+            continue;
+        };
+
+        for contact in contacts {
+            let Ok(particle_system) = particle_systems.get(contact.particle_system) else {
+                continue;
+            };
+            let Some(position) = particle_system
+                .get_positions()
+                .get(contact.particle_index as usize)
+            else {
+                continue;
+            };
+            // the contact's mass is the reduced mass of the pair, which shrinks for heavy bodies,
+            // so the displaced fluid mass is derived from the particle's area instead
+            let definition = particle_system.get_definition();
+            let particle_diameter = 2. * definition.radius;
+            let displaced_mass = definition.density * particle_diameter * particle_diameter;
+            let buoyancy = -gravity * fluid_interaction.buoyancy * displaced_mass * contact.weight;
+            body_ptr
+                .as_mut()
+                .ApplyForce(&to_b2Vec2(&buoyancy), &to_b2Vec2(position), true);
+        }
+
+        let immersion: f32 = contacts.iter().map(|contact| contact.weight).sum();
+        let linear_drag = -body.linear_velocity * fluid_interaction.linear_drag * immersion;
+        let angular_drag = -body.angular_velocity * fluid_interaction.angular_drag * immersion;
+        body_ptr
+            .as_mut()
+            .ApplyForceToCenter(&to_b2Vec2(&linear_drag), true);
+        body_ptr.as_mut().ApplyTorque(angular_drag, true);
+    }
+}
+
+fn apply_soft_body_pressure(
+    mut b2_world: NonSendMut<b2World>,
+    soft_bodies: Query<&SoftBody>,
+    bodies: Query<&b2Body>,
+) {
+    for soft_body in soft_bodies.iter() {
+        let Ok(node_positions) = soft_body
+            .nodes()
+            .iter()
+            .map(|node| bodies.get(*node).map(|body| body.position))
+            .collect::<Result<Vec<Vec2>, _>>()
+        else {
+            continue;
+        };
+        let forces = soft_body.pressure_forces(&node_positions);
+        for (node, force) in soft_body.nodes().iter().zip(forces) {
+            if let Some(body_ptr) = b2_world.get_body_ptr_mut(*node) {
+                body_ptr
+                    .as_mut()
+                    .ApplyForceToCenter(&to_b2Vec2(&force), true);
+            }
+        }
+    }
+}
+
 fn sync_bodies_from_world(b2_world: NonSend<b2World>, mut bodies: Query<(Entity, &mut b2Body)>) {
     for (entity, mut body) in bodies.iter_mut() {
         body.sync_with_world(entity, &b2_world);
     }
 }
 
+fn break_joints(
+    commands: &mut Commands,
+    b2_world: &mut b2World,
+    inv_dt: f32,
+    breakable_joints: &Query<(Entity, &Breakable)>,
+    joints: &Query<&b2Joint>,
+    joint_broke_events: &mut EventWriter<JointBrokeEvent>,
+) {
+    for (entity, breakable) in breakable_joints.iter() {
+        // joints broken in an earlier step of this frame are only despawned at the end of it
+        let Some(joint_ptr) = b2_world.get_joint_ptr(&entity) else {
+            continue;
+        };
+        let (reaction_force, reaction_torque) = joint_ptr.reaction(inv_dt);
+        if !breakable.is_exceeded_by(reaction_force.length(), reaction_torque) {
+            continue;
+        }
+
+        let gear_joint_entities = b2_world.destroy_joint_for_entity(entity);
+        despawn_destroyed_gear_joints(commands, &gear_joint_entities);
+        commands.entity(entity).despawn_recursive();
+        for joint_entity in std::iter::once(entity).chain(gear_joint_entities) {
+            let Ok(joint) = joints.get(joint_entity) else {
+                continue;
+            };
+            joint_broke_events.send(JointBrokeEvent {
+                joint: joint_entity,
+                body_a: *joint.body_a(),
+                body_b: *joint.body_b(),
+            });
+        }
+    }
+}
+
+fn sync_joint_states_from_world(
+    mut b2_world: NonSendMut<b2World>,
+    settings: Res<b2WorldSettings>,
+    mut joint_states: Query<(Entity, &mut JointState)>,
+) {
+    let inv_dt = 1. / settings.time_step;
+    for (entity, mut joint_state) in joint_states.iter_mut() {
+        if let Some(joint_ptr) = b2_world.get_joint_ptr(&entity) {
+            *joint_state = joint_ptr.read_state(inv_dt);
+        }
+    }
+}
+
 fn sync_particle_systems_from_world(
     b2_world: NonSend<b2World>,
     mut particle_systems: Query<(Entity, &mut b2ParticleSystem)>,
@@ -479,6 +1030,8 @@ fn sync_particle_systems_from_world(
 fn send_contact_events(
     mut begin_contact_events: EventWriter<b2BeginContactEvent>,
     mut end_contact_events: EventWriter<b2EndContactEvent>,
+    mut contact_impulse_events: EventWriter<b2ContactImpulseEvent>,
+    mut contact_step_events: EventWriter<b2ContactStepEvent>,
     b2_world: NonSendMut<b2World>,
 ) {
     let contact_listener = b2_world.contact_listener();
@@ -498,11 +1051,214 @@ fn send_contact_events(
         for contact in ended_contacts.values() {
             end_contact_events.send(b2EndContactEvent(contact.clone()))
         }
+
+        contact_step_events.send_batch(contact_listener.contact_step_events().iter().copied());
+
+        for contact in contact_listener.strongest_contact_impulses().values() {
+            contact_impulse_events.send(b2ContactImpulseEvent(contact.clone()));
+        }
     }
 
     contact_listener.clear_contact_changes();
 }
 
+fn refresh_contacts(mut b2_world: NonSendMut<b2World>) {
+    b2_world.refresh_contacts();
+}
+
+fn update_colliding_entities(
+    b2_world: NonSend<b2World>,
+    mut colliding_entities: Query<(Entity, &mut CollidingEntities)>,
+) {
+    let contact_listener = b2_world.contact_listener();
+    let contact_listener = contact_listener.borrow();
+
+    let mut touching: HashMap<Entity, HashSet<Entity>> = HashMap::new();
+    for contact in contact_listener.fixture_contacts().values() {
+        for (entity, other) in [
+            (contact.fixture_a, contact.fixture_b),
+            (contact.fixture_b, contact.fixture_a),
+            (contact.body_a, contact.body_b),
+            (contact.body_b, contact.body_a),
+        ] {
+            if colliding_entities.contains(entity) {
+                touching.entry(entity).or_default().insert(other);
+            }
+        }
+    }
+
+    for (entity, mut colliding_entities) in &mut colliding_entities {
+        let touching = touching.remove(&entity).unwrap_or_default();
+        if **colliding_entities != touching {
+            colliding_entities.set(touching);
+        }
+    }
+}
+
+fn run_contact_callbacks(
+    mut commands: Commands,
+    mut begin_contact_events: EventReader<b2BeginContactEvent>,
+    mut end_contact_events: EventReader<b2EndContactEvent>,
+    callbacks: Query<&ContactCallbacks>,
+) {
+    for event in begin_contact_events.read() {
+        let contact = &event.0;
+        for entity in [
+            contact.fixture_a,
+            contact.fixture_b,
+            contact.body_a,
+            contact.body_b,
+        ] {
+            if let Ok(callbacks) = callbacks.get(entity) {
+                callbacks.run_begin(&mut commands, entity, contact);
+            }
+        }
+    }
+
+    for event in end_contact_events.read() {
+        let contact = &event.0;
+        for entity in [
+            contact.fixture_a,
+            contact.fixture_b,
+            contact.body_a,
+            contact.body_b,
+        ] {
+            if let Ok(callbacks) = callbacks.get(entity) {
+                callbacks.run_end(&mut commands, entity, contact);
+            }
+        }
+    }
+}
+
+fn send_sensor_events(
+    mut contact_step_events: EventReader<b2ContactStepEvent>,
+    mut sensor_enter_events: EventWriter<SensorEnterEvent>,
+    mut sensor_exit_events: EventWriter<SensorExitEvent>,
+    mut active_visits: Local<HashSet<(Entity, Entity)>>,
+    fixtures: Query<&b2Fixture>,
+    sensor_filters: Query<&SensorFilter>,
+    entities: Query<EntityRef>,
+) {
+    // The ordered stream keeps a visitor leaving and re-entering within one frame's steps from
+    // being reported as two enters followed by an exit.
+    for event in contact_step_events.read() {
+        let contact = &event.contact;
+        match event.kind {
+            b2ContactEventKind::Begin => {
+                for (sensor, sensor_body, visitor, visitor_body) in [
+                    (
+                        contact.fixture_a,
+                        contact.body_a,
+                        contact.fixture_b,
+                        contact.body_b,
+                    ),
+                    (
+                        contact.fixture_b,
+                        contact.body_b,
+                        contact.fixture_a,
+                        contact.body_a,
+                    ),
+                ] {
+                    let (Ok(sensor_fixture), Ok(visitor_fixture)) =
+                        (fixtures.get(sensor), fixtures.get(visitor))
+                    else {
+                        continue;
+                    };
+                    if !sensor_fixture.def().is_sensor {
+                        continue;
+                    }
+
+                    if let Ok(sensor_filter) = sensor_filters.get(sensor) {
+                        let has_component = |type_id| {
+                            [visitor, visitor_body].iter().any(|entity| {
+                                entities
+                                    .get(*entity)
+                                    .map_or(false, |entity| entity.contains_type_id(type_id))
+                            })
+                        };
+                        if !sensor_filter
+                            .accepts(visitor_fixture.def().filter.category, has_component)
+                        {
+                            continue;
+                        }
+                    }
+
+                    if !active_visits.insert((sensor, visitor)) {
+                        continue;
+                    }
+                    sensor_enter_events.send(SensorEnterEvent {
+                        sensor,
+                        sensor_body,
+                        visitor,
+                        visitor_body,
+                    });
+                }
+            }
+            // Either fixture might be gone by now, so exits are matched against the enters that
+            // were actually sent instead of looking the fixtures up again.
+            b2ContactEventKind::End => {
+                for (sensor, sensor_body, visitor, visitor_body) in [
+                    (
+                        contact.fixture_a,
+                        contact.body_a,
+                        contact.fixture_b,
+                        contact.body_b,
+                    ),
+                    (
+                        contact.fixture_b,
+                        contact.body_b,
+                        contact.fixture_a,
+                        contact.body_a,
+                    ),
+                ] {
+                    if active_visits.remove(&(sensor, visitor)) {
+                        sensor_exit_events.send(SensorExitEvent {
+                            sensor,
+                            sensor_body,
+                            visitor,
+                            visitor_body,
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn send_particle_contact_events(
+    mut particle_body_begin_events: EventWriter<b2ParticleBodyBeginEvent>,
+    mut particle_body_end_events: EventWriter<b2ParticleBodyEndEvent>,
+    mut particle_particle_begin_events: EventWriter<b2ParticleParticleBeginEvent>,
+    mut particle_particle_end_events: EventWriter<b2ParticleParticleEndEvent>,
+    b2_world: NonSendMut<b2World>,
+) {
+    let contact_listener = b2_world.contact_listener();
+    let mut contact_listener = contact_listener.borrow_mut();
+
+    particle_body_begin_events.send_batch(
+        contact_listener
+            .particle_body_begin_events()
+            .iter()
+            .copied(),
+    );
+    particle_body_end_events
+        .send_batch(contact_listener.particle_body_end_events().iter().copied());
+    particle_particle_begin_events.send_batch(
+        contact_listener
+            .particle_particle_begin_events()
+            .iter()
+            .copied(),
+    );
+    particle_particle_end_events.send_batch(
+        contact_listener
+            .particle_particle_end_events()
+            .iter()
+            .copied(),
+    );
+
+    contact_listener.clear_particle_contact_changes();
+}
+
 fn copy_particle_system_contacts(
     b2_world: NonSendMut<b2World>,
     mut particle_systems: Query<(Entity, &mut b2ParticleSystemContacts)>,
@@ -524,30 +1280,77 @@ fn copy_particle_system_contacts(
                 .iter()
                 .map(|c| b2ParticleBodyContact::from_ffi_contact(c)),
         );
+
+        let particle_contacts = unsafe {
+            let particle_contacts = particle_system_ptr.as_ref().GetContacts();
+            let count =
+                i32::from(int32::from(particle_system_ptr.as_ref().GetContactCount())) as usize;
+            std::slice::from_raw_parts(particle_contacts, count)
+        };
+
+        let new_particle_contacts = particle_system_contacts.particle_contacts_mut();
+        new_particle_contacts.clear();
+        new_particle_contacts.extend(
+            particle_contacts
+                .iter()
+                .map(|c| b2ParticleContact::from_ffi_contact(c)),
+        );
     }
 }
 
 fn update_particle_body_contacts_components(
-    mut particle_contact_components: Query<(Entity, &mut b2ParticleContacts), With<b2Body>>,
-    particle_system_contacts: Query<&b2ParticleSystemContacts>,
+    mut particle_contact_components: Query<&mut b2ParticleContacts, With<b2Body>>,
+    particle_system_contacts: Query<(Entity, &b2ParticleSystemContacts)>,
 ) {
-    for (_, mut particle_contact_component) in &mut particle_contact_components {
+    for mut particle_contact_component in &mut particle_contact_components {
         particle_contact_component.contacts_mut().clear();
     }
 
-    for particle_system in &particle_system_contacts {
-        for contact in particle_system.body_contacts() {
-            for (entity, mut particle_contact_component) in &mut particle_contact_components {
-                if contact.body == entity {
-                    particle_contact_component
-                        .contacts_mut()
-                        .insert(contact.particle_index);
-                }
-            }
+    for (particle_system, particle_system_contacts) in &particle_system_contacts {
+        for contact in particle_system_contacts.body_contacts() {
+            let Ok(mut particle_contact_component) =
+                particle_contact_components.get_mut(contact.body)
+            else {
+                continue;
+            };
+            particle_contact_component
+                .contacts_mut()
+                .push(b2BodyParticleContact {
+                    particle_system,
+                    particle_index: contact.particle_index,
+                    fixture: contact.fixture,
+                    weight: contact.weight,
+                    normal: contact.normal,
+                    mass: contact.mass,
+                });
         }
     }
 }
 
+fn update_soft_body_meshes(
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    soft_bodies: Query<(&SoftBody, &Mesh2dHandle)>,
+    bodies: Query<&b2Body>,
+) {
+    let Some(mut meshes) = meshes else {
+        return;
+    };
+    for (soft_body, mesh_handle) in soft_bodies.iter() {
+        let Some(mesh) = meshes.get_mut(&mesh_handle.0) else {
+            continue;
+        };
+        let Ok(node_positions) = soft_body
+            .nodes()
+            .iter()
+            .map(|node| bodies.get(*node).map(|body| body.position))
+            .collect::<Result<Vec<Vec2>, _>>()
+        else {
+            continue;
+        };
+        soft_body.update_mesh(mesh, &node_positions);
+    }
+}
+
 fn update_transforms(
     mut bodies: Query<(&b2Body, &mut Transform)>,
     physics_time_accumulator: Res<PhysicsTimeAccumulator>,
@@ -596,7 +1399,7 @@ fn draw_fixtures(
             b2Shape::Circle { radius, position } => {
                 gizmos.circle_2d(to_global(transform, *position), *radius, color);
             }
-            b2Shape::EdgeTwoSided { v1, v2 } => {
+            b2Shape::EdgeTwoSided { v1, v2 } | b2Shape::EdgeOneSided { v1, v2, .. } => {
                 gizmos.line_2d(to_global(transform, *v1), to_global(transform, *v2), color);
             }
             b2Shape::Polygon { vertices } | b2Shape::ChainLoop { vertices } => {
@@ -650,3 +1453,198 @@ fn draw_particle_systems(
         });
     }
 }
+
+/// Drag-and-drop of dynamic bodies with a mouse joint. Pressing picks the topmost dynamic body
+/// under the cursor, moving drags it along and releasing lets it go. The topmost body is the one
+/// with the highest [`DragPriority`], and among equal priorities the most recently spawned one.
+///
+/// By default the cursor and left mouse button of the primary window's camera are used. With
+/// [`DragPlugin::manual`] the [`DragInput`] resource has to be updated instead, e.g. for touch
+/// input or multiple cameras.
+pub struct DragPlugin {
+    pub read_mouse: bool,
+}
+
+impl Default for DragPlugin {
+    fn default() -> Self {
+        Self { read_mouse: true }
+    }
+}
+
+impl DragPlugin {
+    pub fn manual() -> Self {
+        Self { read_mouse: false }
+    }
+}
+
+impl Plugin for DragPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DragInput>()
+            .init_resource::<DragSettings>()
+            .init_resource::<DragState>()
+            .add_systems(Update, update_drag);
+        if self.read_mouse {
+            app.add_systems(Update, read_drag_input_from_mouse.before(update_drag));
+        }
+    }
+}
+
+/// Decides which of several overlapping bodies [`DragPlugin`] picks; the highest priority wins.
+/// Bodies without this component have a priority of zero.
+#[derive(Component, Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DragPriority(pub i32);
+
+#[derive(Resource, Debug, Default)]
+pub struct DragInput {
+    /// The cursor position in world coordinates, if the cursor is available.
+    pub cursor_position: Option<Vec2>,
+    pub pressed: bool,
+}
+
+#[derive(Resource, Debug)]
+pub struct DragSettings {
+    /// The maximum force of the mouse joint, relative to the mass of the dragged body.
+    pub max_force_per_mass: f32,
+    /// The frequency of the spring pulling the body towards the cursor, in Hz.
+    pub frequency_hz: f32,
+    pub damping_ratio: f32,
+}
+
+impl Default for DragSettings {
+    fn default() -> Self {
+        Self {
+            max_force_per_mass: 1000.,
+            frequency_hz: 5.,
+            damping_ratio: 0.7,
+        }
+    }
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct DragState {
+    ground_body: Option<Entity>,
+    joint: Option<Entity>,
+    dragged_body: Option<Entity>,
+    was_pressed: bool,
+}
+
+impl DragState {
+    pub fn dragged_body(&self) -> Option<Entity> {
+        self.dragged_body
+    }
+}
+
+fn read_drag_input_from_mouse(
+    input: Res<Input<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut drag_input: ResMut<DragInput>,
+) {
+    drag_input.pressed = input.pressed(MouseButton::Left);
+    drag_input.cursor_position = windows
+        .get_single()
+        .ok()
+        .and_then(|window| window.cursor_position())
+        .zip(cameras.get_single().ok())
+        .and_then(|(cursor_position, (camera, camera_transform))| {
+            camera.viewport_to_world_2d(camera_transform, cursor_position)
+        });
+}
+
+fn update_drag(
+    mut commands: Commands,
+    mut b2_world: NonSendMut<b2World>,
+    drag_input: Res<DragInput>,
+    settings: Res<DragSettings>,
+    mut state: ResMut<DragState>,
+    mut bodies: Query<(&mut b2Body, Option<&DragPriority>)>,
+    mut joints: Query<&mut b2MouseJoint>,
+) {
+    // the dragged body might have been despawned, taking the joint along with it
+    if let Some(dragged_body) = state.dragged_body {
+        if !bodies.contains(dragged_body) {
+            state.dragged_body = None;
+            state.joint = None;
+        }
+    }
+
+    let just_pressed = drag_input.pressed && !state.was_pressed;
+    state.was_pressed = drag_input.pressed;
+
+    if !drag_input.pressed {
+        if let Some(joint) = state.joint.take() {
+            if let Some(mut joint) = commands.get_entity(joint) {
+                joint.despawn();
+            }
+        }
+        state.dragged_body = None;
+        return;
+    }
+
+    let Some(cursor_position) = drag_input.cursor_position else {
+        return;
+    };
+
+    if let Some(joint) = state.joint {
+        if let Ok(mut joint) = joints.get_mut(joint) {
+            if joint.target != cursor_position {
+                joint.target = cursor_position;
+            }
+        }
+        return;
+    }
+
+    // only pick on the initial press, so sweeping a held button over bodies doesn't grab them
+    if !just_pressed {
+        return;
+    }
+
+    // query_point doesn't return the bodies in any particular order. Entity indices grow with
+    // every spawn, so the highest one is the most recently spawned body, unless its index was
+    // recycled from a despawned entity.
+    let picked_body = b2_world
+        .query_point(&cursor_position)
+        .into_iter()
+        .filter_map(|entity| {
+            let (body, priority) = bodies.get(entity).ok()?;
+            matches!(body.body_type, b2BodyType::Dynamic).then_some((
+                priority.copied().unwrap_or_default(),
+                entity.index(),
+                entity,
+            ))
+        })
+        .max();
+    let Some((_, _, picked_body)) = picked_body else {
+        return;
+    };
+
+    // the ground body may have been despawned by someone else in the meantime
+    let ground_body = match state.ground_body {
+        Some(ground_body) if bodies.contains(ground_body) => ground_body,
+        _ => commands.spawn(b2BodyBundle::default()).id(),
+    };
+    state.ground_body = Some(ground_body);
+
+    let (mut body, _) = bodies.get_mut(picked_body).unwrap();
+    body.awake = true;
+    let mass = body.mass();
+    let omega = 2. * PI * settings.frequency_hz;
+    let joint_def = b2MouseJointDef {
+        target: cursor_position,
+        max_force: settings.max_force_per_mass * mass,
+        stiffness: mass * omega * omega,
+        damping: 2. * mass * settings.damping_ratio * omega,
+    };
+    let joint = commands
+        .spawn_empty()
+        .add(CreateMouseJoint::new(
+            ground_body,
+            picked_body,
+            true,
+            &joint_def,
+        ))
+        .id();
+
+    state.joint = Some(joint);
+    state.dragged_body = Some(picked_body);
+}
@@ -0,0 +1,55 @@
+//! Joints breaking once their reaction force exceeds a `Breakable` threshold.
+
+mod common;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::dynamics::{b2Body, Breakable, JointBrokeEvent};
+
+use common::*;
+
+/// Hangs the pendulum from a joint that breaks above `max_force`, and returns the broken joint
+/// events along with the bob.
+fn hang_from_breakable_joint(max_force: f32) -> (Vec<JointBrokeEvent>, App, Entity, Entity) {
+    let mut app = physics_app(GRAVITY);
+    let (_, bob, joint) = spawn_pendulum(&mut app.world);
+    app.world.entity_mut(joint).insert(Breakable {
+        max_force,
+        ..default()
+    });
+
+    let mut broke_events = Vec::new();
+    for _ in 0..60 {
+        app.update();
+        broke_events.extend(events::<JointBrokeEvent>(&app));
+    }
+    (broke_events, app, bob, joint)
+}
+
+#[test]
+fn joints_hold_below_the_threshold() {
+    // the bob weighs about 9.81 N
+    let (broke_events, app, bob, joint) = hang_from_breakable_joint(20.);
+    assert!(broke_events.is_empty());
+    assert!(app.world.get_entity(joint).is_some());
+    let position = app.world.get::<b2Body>(bob).unwrap().position;
+    assert!(
+        (position.y + PENDULUM_LENGTH).abs() < 0.1,
+        "bob hangs at {position}"
+    );
+}
+
+#[test]
+fn joints_break_above_the_threshold() {
+    let (broke_events, app, bob, joint) = hang_from_breakable_joint(5.);
+    assert_eq!(broke_events.len(), 1);
+    let broke_event = broke_events[0];
+    assert_eq!(broke_event.joint, joint);
+    assert_eq!(broke_event.body_b, bob);
+    assert!(app.world.get_entity(joint).is_none());
+    let position = app.world.get::<b2Body>(bob).unwrap().position;
+    assert!(
+        position.y < -PENDULUM_LENGTH - 2.,
+        "bob hangs at {position}"
+    );
+}
@@ -0,0 +1,124 @@
+//! A box sliding over a floor made of many short edges must not catch on the seams between
+//! them, which is what the ghost vertices of chains and one-sided edges are for.
+
+extern crate bevy;
+extern crate bevy_liquidfun;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+
+use bevy_liquidfun::collision::b2Shape;
+use bevy_liquidfun::dynamics::{
+    b2Body, b2BodyBundle, b2BodyDef, b2BodyType::Dynamic, b2Fixture, b2FixtureDef, b2World,
+    b2WorldSettings,
+};
+use bevy_liquidfun::plugins::LiquidFunPlugin;
+
+const FLOOR_LENGTH: i32 = 30;
+const SLIDE_SPEED: f32 = 8.;
+const MAX_VERTICAL_SPEED: f32 = 0.05;
+const MAX_HEIGHT_DEVIATION: f32 = 0.01;
+const MAX_ANGLE: f32 = 0.01;
+
+#[test]
+fn box_slides_across_chain_seams() {
+    slide_box_across_seams(|world, ground_entity| {
+        // edges only collide on their right side, so a floor runs from right to left
+        let vertices = (0..=FLOOR_LENGTH)
+            .rev()
+            .map(|x| Vec2::new(x as f32, 0.))
+            .collect();
+        let shape = b2Shape::Chain {
+            vertices,
+            prev_vertex: Vec2::new((FLOOR_LENGTH + 1) as f32, 0.),
+            next_vertex: Vec2::new(-1., 0.),
+        };
+        world.spawn(b2Fixture::new(ground_entity, &b2FixtureDef::new(shape, 0.)));
+    });
+}
+
+#[test]
+fn box_slides_across_one_sided_edge_seams() {
+    slide_box_across_seams(|world, ground_entity| {
+        for x in 0..FLOOR_LENGTH {
+            let shape = b2Shape::EdgeOneSided {
+                v0: Vec2::new((x + 2) as f32, 0.),
+                v1: Vec2::new((x + 1) as f32, 0.),
+                v2: Vec2::new(x as f32, 0.),
+                v3: Vec2::new((x - 1) as f32, 0.),
+            };
+            world.spawn(b2Fixture::new(ground_entity, &b2FixtureDef::new(shape, 0.)));
+        }
+    });
+}
+
+fn slide_box_across_seams(spawn_floor: impl FnOnce(&mut World, Entity)) {
+    let time_step = b2WorldSettings::default().time_step;
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, LiquidFunPlugin::default()))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            time_step,
+        )))
+        .insert_non_send_resource(b2World::new(Vec2::new(0., -9.81)));
+
+    let ground_entity = app.world.spawn(b2BodyBundle::default()).id();
+    spawn_floor(&mut app.world, ground_entity);
+
+    let body_def = b2BodyDef {
+        body_type: Dynamic,
+        position: Vec2::new(2., 0.5),
+        ..default()
+    };
+    let box_entity = app.world.spawn(b2BodyBundle::new(&body_def)).id();
+    let fixture_def = b2FixtureDef {
+        friction: 0.,
+        ..b2FixtureDef::new(b2Shape::create_box(0.5, 0.5), 1.)
+    };
+    app.world.spawn(b2Fixture::new(box_entity, &fixture_def));
+
+    // let the box settle on the floor before pushing it
+    for _ in 0..60 {
+        app.update();
+    }
+    let rest_height = app.world.get::<b2Body>(box_entity).unwrap().position.y;
+    app.world
+        .get_mut::<b2Body>(box_entity)
+        .unwrap()
+        .linear_velocity = Vec2::new(SLIDE_SPEED, 0.);
+
+    let end_x = (FLOOR_LENGTH - 3) as f32;
+    let max_steps = (2. * end_x / SLIDE_SPEED / time_step) as usize;
+    for _ in 0..max_steps {
+        app.update();
+
+        let body = app.world.get::<b2Body>(box_entity).unwrap();
+        let x = body.position.x;
+        assert!(
+            body.linear_velocity.y < MAX_VERTICAL_SPEED,
+            "box bumped upwards with {} m/s at x = {}",
+            body.linear_velocity.y,
+            x
+        );
+        assert!(
+            (body.position.y - rest_height).abs() < MAX_HEIGHT_DEVIATION,
+            "box left the floor height {} with {} at x = {}",
+            rest_height,
+            body.position.y,
+            x
+        );
+        assert!(
+            body.angle.abs() < MAX_ANGLE,
+            "box tipped over to {} rad at x = {}",
+            body.angle,
+            x
+        );
+        if x > end_x {
+            return;
+        }
+    }
+
+    let x = app.world.get::<b2Body>(box_entity).unwrap().position.x;
+    panic!("box got stuck at x = {x} before reaching x = {end_x}");
+}
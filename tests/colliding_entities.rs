@@ -0,0 +1,64 @@
+//! `CollidingEntities` following contacts as they begin and end.
+
+mod common;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::dynamics::{b2Body, CollidingEntities};
+
+use common::*;
+
+fn colliding(app: &App, entity: Entity) -> Vec<Entity> {
+    app.world
+        .get::<CollidingEntities>(entity)
+        .unwrap()
+        .iter()
+        .copied()
+        .collect()
+}
+
+#[test]
+fn tracks_touching_bodies_and_fixtures() {
+    let mut app = physics_app(GRAVITY);
+    let (ground, ground_fixture) = spawn_ground(&mut app.world, 0.);
+    let (body, fixture) = spawn_box(&mut app.world, Vec2::new(0., 2.), 0.5);
+    app.world
+        .entity_mut(body)
+        .insert(CollidingEntities::default());
+    app.world
+        .entity_mut(fixture)
+        .insert(CollidingEntities::default());
+    app.world
+        .entity_mut(ground)
+        .insert(CollidingEntities::default());
+
+    step(&mut app, 2);
+    assert_eq!(colliding(&app, body), vec![]);
+
+    step(&mut app, 60);
+    assert_eq!(colliding(&app, body), vec![ground]);
+    assert_eq!(colliding(&app, fixture), vec![ground_fixture]);
+    assert_eq!(colliding(&app, ground), vec![body]);
+
+    app.world.get_mut::<b2Body>(body).unwrap().position = Vec2::new(0., 5.);
+    step(&mut app, 2);
+    assert_eq!(colliding(&app, body), vec![]);
+    assert_eq!(colliding(&app, fixture), vec![]);
+    assert_eq!(colliding(&app, ground), vec![]);
+}
+
+#[test]
+fn removes_despawned_bodies() {
+    let mut app = physics_app(GRAVITY);
+    let (ground, _) = spawn_ground(&mut app.world, 0.);
+    let (body, _) = spawn_box(&mut app.world, Vec2::new(0., 0.5), 0.5);
+    app.world
+        .entity_mut(ground)
+        .insert(CollidingEntities::default());
+    step(&mut app, 10);
+    assert_eq!(colliding(&app, ground), vec![body]);
+
+    app.world.despawn(body);
+    step(&mut app, 2);
+    assert_eq!(colliding(&app, ground), vec![]);
+}
@@ -0,0 +1,84 @@
+//! Collision layers and the layer matrix deciding which fixtures collide.
+
+mod common;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::dynamics::{b2Body, CollisionLayerMatrix, CollisionLayers, PhysicsLayer};
+
+use common::*;
+
+#[derive(Debug, Copy, Clone)]
+enum Layer {
+    Ground,
+    Player,
+    Ghost,
+}
+
+impl PhysicsLayer for Layer {
+    fn index(self) -> u8 {
+        self as u8
+    }
+}
+
+fn height(app: &App, body: Entity) -> f32 {
+    app.world.get::<b2Body>(body).unwrap().position.y
+}
+
+/// A player and a ghost box resting on the ground, returned as `(player, ghost)`.
+fn layered_app(matrix: CollisionLayerMatrix) -> (App, Entity, Entity) {
+    let mut app = physics_app(GRAVITY);
+    app.insert_resource(matrix);
+    let (_, ground) = spawn_ground(&mut app.world, 0.);
+    app.world
+        .entity_mut(ground)
+        .insert(CollisionLayers::new([Layer::Ground]));
+    let [player, ghost] = [(-2., Layer::Player), (2., Layer::Ghost)].map(|(x, layer)| {
+        let (body, fixture) = spawn_box(&mut app.world, Vec2::new(x, 0.5), 0.5);
+        app.world
+            .entity_mut(fixture)
+            .insert(CollisionLayers::new([layer]));
+        body
+    });
+    (app, player, ghost)
+}
+
+#[test]
+fn blocked_layer_pairs_pass_through_each_other() {
+    let mut matrix = CollisionLayerMatrix::default();
+    matrix.set_interaction(Layer::Ghost, Layer::Ground, false);
+    let (mut app, player, ghost) = layered_app(matrix);
+
+    step(&mut app, 60);
+    assert!(height(&app, player) > 0.4);
+    assert!(height(&app, ghost) < -1.);
+}
+
+#[test]
+fn changing_the_matrix_refilters_existing_fixtures() {
+    let (mut app, player, ghost) = layered_app(CollisionLayerMatrix::default());
+    step(&mut app, 30);
+    assert!(height(&app, ghost) > 0.4);
+
+    app.world
+        .resource_mut::<CollisionLayerMatrix>()
+        .set_interaction(Layer::Ghost, Layer::Ground, false);
+    step(&mut app, 60);
+    assert!(height(&app, player) > 0.4);
+    assert!(height(&app, ghost) < -1.);
+}
+
+#[test]
+fn explicit_filters_override_the_matrix() {
+    let (mut app, player, ghost) = layered_app(CollisionLayerMatrix::default());
+    let mut layers = app.world.query::<&mut CollisionLayers>();
+    for mut layers in layers.iter_mut(&mut app.world) {
+        if layers.contains(Layer::Ghost) {
+            *layers = CollisionLayers::new([Layer::Ghost]).with_filters([Layer::Player]);
+        }
+    }
+
+    step(&mut app, 60);
+    assert!(height(&app, player) > 0.4);
+    assert!(height(&app, ghost) < -1.);
+}
@@ -0,0 +1,121 @@
+//! Helpers for stepping a physics app deterministically in integration tests.
+
+#![allow(dead_code)]
+
+use std::time::Duration;
+
+use bevy::ecs::system::{CommandQueue, EntityCommand};
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+
+use bevy_liquidfun::collision::b2Shape;
+use bevy_liquidfun::dynamics::{
+    b2BodyBundle, b2BodyDef, b2BodyType, b2DistanceJointDef, b2Fixture, b2FixtureDef, b2World,
+    b2WorldSettings, CreateDistanceJoint,
+};
+use bevy_liquidfun::plugins::LiquidFunPlugin;
+
+/// Exactly representable both as `f32` and as a `Duration`, so that every update runs exactly
+/// one physics step.
+pub const TIME_STEP: f32 = 1. / 64.;
+
+pub const GRAVITY: Vec2 = Vec2::new(0., -9.81);
+
+/// An app without rendering or windowing that runs one physics step per update. The first update
+/// doesn't advance the time, so it only creates the spawned bodies, fixtures and joints.
+pub fn physics_app(gravity: Vec2) -> App {
+    let settings = b2WorldSettings {
+        time_step: TIME_STEP,
+        ..default()
+    };
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, LiquidFunPlugin::new(settings)))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            TIME_STEP,
+        )))
+        .insert_non_send_resource(b2World::new(gravity));
+    app
+}
+
+/// Makes every following update run the given number of physics steps.
+pub fn set_steps_per_update(app: &mut App, steps: u32) {
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        TIME_STEP * steps as f32,
+    )));
+}
+
+pub fn step(app: &mut App, steps: usize) {
+    for _ in 0..steps {
+        app.update();
+    }
+}
+
+/// The events of the given type sent during the last update.
+pub fn events<T: Event + Clone>(app: &App) -> Vec<T> {
+    let events = app.world.resource::<Events<T>>();
+    events.get_reader().read(events).cloned().collect()
+}
+
+/// Runs `spawn` with `Commands` for the app's world and applies them right away.
+pub fn with_commands<R>(app: &mut App, spawn: impl FnOnce(&mut Commands) -> R) -> R {
+    let mut queue = CommandQueue::default();
+    let result = spawn(&mut Commands::new(&mut queue, &app.world));
+    queue.apply(&mut app.world);
+    result
+}
+
+pub fn count<T: Component>(app: &mut App) -> usize {
+    app.world.query::<&T>().iter(&app.world).count()
+}
+
+pub fn spawn_body(world: &mut World, body_type: b2BodyType, position: Vec2) -> Entity {
+    let body_def = b2BodyDef {
+        body_type,
+        position,
+        ..default()
+    };
+    world.spawn(b2BodyBundle::new(&body_def)).id()
+}
+
+pub fn spawn_fixture(world: &mut World, body: Entity, shape: b2Shape, density: f32) -> Entity {
+    world
+        .spawn(b2Fixture::new(body, &b2FixtureDef::new(shape, density)))
+        .id()
+}
+
+/// A dynamic body with a box fixture, returned as `(body, fixture)`.
+pub fn spawn_box(world: &mut World, position: Vec2, half_size: f32) -> (Entity, Entity) {
+    let body = spawn_body(world, b2BodyType::Dynamic, position);
+    let fixture = spawn_fixture(world, body, b2Shape::create_box(half_size, half_size), 1.);
+    (body, fixture)
+}
+
+/// A static body with a horizontal edge fixture at the given height, returned as
+/// `(body, fixture)`.
+pub fn spawn_ground(world: &mut World, height: f32) -> (Entity, Entity) {
+    let body = spawn_body(world, b2BodyType::Static, Vec2::ZERO);
+    let shape = b2Shape::EdgeTwoSided {
+        v1: Vec2::new(-40., height),
+        v2: Vec2::new(40., height),
+    };
+    let fixture = spawn_fixture(world, body, shape, 0.);
+    (body, fixture)
+}
+
+pub const PENDULUM_LENGTH: f32 = 5.;
+
+/// A box of mass 1 hanging from a static anchor at the origin by a rigid distance joint,
+/// returned as `(anchor, bob, joint)`.
+pub fn spawn_pendulum(world: &mut World) -> (Entity, Entity, Entity) {
+    let anchor = spawn_body(world, b2BodyType::Static, Vec2::ZERO);
+    let (bob, _) = spawn_box(world, Vec2::new(0., -PENDULUM_LENGTH), 0.5);
+    let def = b2DistanceJointDef {
+        length: PENDULUM_LENGTH,
+        min_length: PENDULUM_LENGTH,
+        max_length: PENDULUM_LENGTH,
+        ..default()
+    };
+    let joint = world.spawn_empty().id();
+    CreateDistanceJoint::new(anchor, bob, false, &def).apply(joint, world);
+    (anchor, bob, joint)
+}
@@ -0,0 +1,40 @@
+//! Contact filters vetoing collisions, including contacts that already exist.
+
+mod common;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::dynamics::{b2Body, b2ContactFilter, b2World};
+
+use common::*;
+
+struct IgnoreFixture(Entity);
+
+impl b2ContactFilter for IgnoreFixture {
+    fn should_collide(
+        &mut self,
+        fixture_a: Entity,
+        _body_a: Entity,
+        fixture_b: Entity,
+        _body_b: Entity,
+    ) -> bool {
+        fixture_a != self.0 && fixture_b != self.0
+    }
+}
+
+#[test]
+fn filter_installed_at_runtime_drops_existing_contacts() {
+    let mut app = physics_app(GRAVITY);
+    let (_, ground) = spawn_ground(&mut app.world, 0.);
+    let (body, _) = spawn_box(&mut app.world, Vec2::new(0., 0.5), 0.5);
+    step(&mut app, 60);
+    let position = app.world.get::<b2Body>(body).unwrap().position;
+    assert!(position.y > 0.4, "body sank to {position} before filtering");
+
+    app.world
+        .non_send_resource_mut::<b2World>()
+        .set_contact_filter(IgnoreFixture(ground));
+    step(&mut app, 60);
+    let position = app.world.get::<b2Body>(body).unwrap().position;
+    assert!(position.y < -1., "body still rests at {position}");
+}
@@ -0,0 +1,50 @@
+//! Contact impulse events and their suppression below a body's threshold.
+
+mod common;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::dynamics::{b2ContactImpulseEvent, ContactImpulseThreshold};
+
+use common::*;
+
+/// Drops a box onto the ground and returns the number of impulse events of every update.
+fn drop_box(threshold: Option<f32>) -> Vec<usize> {
+    let mut app = physics_app(GRAVITY);
+    spawn_ground(&mut app.world, 0.);
+    let (body, _) = spawn_box(&mut app.world, Vec2::new(0., 5.), 0.5);
+    if let Some(threshold) = threshold {
+        app.world
+            .entity_mut(body)
+            .insert(ContactImpulseThreshold(threshold));
+    }
+
+    (0..120)
+        .map(|_| {
+            app.update();
+            events::<b2ContactImpulseEvent>(&app).len()
+        })
+        .collect()
+}
+
+#[test]
+fn resting_contacts_send_impulse_events_every_step() {
+    let event_counts = drop_box(None);
+    assert!(event_counts[100..].iter().all(|count| *count == 1));
+}
+
+#[test]
+fn impulses_below_the_threshold_are_suppressed() {
+    let event_counts = drop_box(Some(1.));
+    assert!(
+        event_counts.iter().any(|count| *count > 0),
+        "the impact was not reported"
+    );
+    assert!(event_counts[100..].iter().all(|count| *count == 0));
+}
+
+#[test]
+fn impacts_below_the_threshold_are_suppressed() {
+    let event_counts = drop_box(Some(1000.));
+    assert!(event_counts.iter().all(|count| *count == 0));
+}
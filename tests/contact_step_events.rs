@@ -0,0 +1,80 @@
+//! The ordered stream of contact begins and ends within a frame's physics steps.
+
+mod common;
+
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::collision::b2Shape;
+use bevy_liquidfun::dynamics::{
+    b2Body, b2BodyType, b2ContactEventKind, b2ContactStepEvent, b2Fixture, b2FixtureDef,
+};
+
+use common::*;
+
+fn step_events(app: &App) -> Vec<(b2ContactEventKind, u32)> {
+    events::<b2ContactStepEvent>(app)
+        .into_iter()
+        .map(|event| (event.kind, event.step))
+        .collect()
+}
+
+/// A small sensor next to the pivot of a rod that turns almost a quarter turn per step, so that
+/// the rod touches the sensor on every other step. Returns `(sensor_body, rod_body)`.
+fn spawn_sensor_and_rod(world: &mut World) -> (Entity, Entity) {
+    // contacts need a dynamic body, and the rod is kinematic
+    let sensor_body = spawn_body(world, b2BodyType::Dynamic, Vec2::new(2., 0.));
+    let fixture_def = b2FixtureDef {
+        is_sensor: true,
+        ..b2FixtureDef::new(
+            b2Shape::Circle {
+                radius: 0.1,
+                position: Vec2::ZERO,
+            },
+            1.,
+        )
+    };
+    world.spawn(b2Fixture::new(sensor_body, &fixture_def));
+
+    let rod_body = spawn_body(world, b2BodyType::Kinematic, Vec2::ZERO);
+    world.get_mut::<b2Body>(rod_body).unwrap().angular_velocity = 0.495 * PI / TIME_STEP;
+    spawn_fixture(world, rod_body, b2Shape::create_box(3., 0.1), 1.);
+    (sensor_body, rod_body)
+}
+
+#[test]
+fn begins_and_ends_keep_their_step_order() {
+    let mut app = physics_app(Vec2::ZERO);
+    set_steps_per_update(&mut app, 3);
+    spawn_sensor_and_rod(&mut app.world);
+
+    step(&mut app, 2);
+    assert_eq!(
+        step_events(&app),
+        vec![
+            (b2ContactEventKind::Begin, 0),
+            (b2ContactEventKind::End, 1),
+            (b2ContactEventKind::Begin, 2),
+        ]
+    );
+}
+
+#[test]
+fn destroying_a_touching_body_ends_its_contacts() {
+    let mut app = physics_app(Vec2::ZERO);
+    let (sensor_body, rod_body) = spawn_sensor_and_rod(&mut app.world);
+    app.world
+        .get_mut::<b2Body>(rod_body)
+        .unwrap()
+        .angular_velocity = 0.;
+
+    step(&mut app, 2);
+    assert_eq!(step_events(&app), vec![(b2ContactEventKind::Begin, 0)]);
+    step(&mut app, 2);
+    assert_eq!(step_events(&app), vec![]);
+
+    app.world.despawn(sensor_body);
+    app.update();
+    assert_eq!(step_events(&app), vec![(b2ContactEventKind::End, 0)]);
+}
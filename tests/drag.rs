@@ -0,0 +1,97 @@
+//! Picking, dragging and releasing bodies with the `DragPlugin`.
+
+mod common;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::dynamics::{b2Body, b2MouseJoint};
+use bevy_liquidfun::plugins::{DragInput, DragPlugin, DragPriority, DragState};
+
+use common::*;
+
+fn drag_app() -> App {
+    let mut app = physics_app(Vec2::ZERO);
+    app.add_plugins(DragPlugin::manual());
+    app
+}
+
+fn set_input(app: &mut App, cursor_position: Vec2, pressed: bool) {
+    let mut input = app.world.resource_mut::<DragInput>();
+    input.cursor_position = Some(cursor_position);
+    input.pressed = pressed;
+}
+
+fn dragged_body(app: &App) -> Option<Entity> {
+    app.world.resource::<DragState>().dragged_body()
+}
+
+fn mouse_joint_count(app: &mut App) -> usize {
+    app.world.query::<&b2MouseJoint>().iter(&app.world).count()
+}
+
+#[test]
+fn drags_the_body_under_the_cursor_and_releases_it() {
+    let mut app = drag_app();
+    let (body, _) = spawn_box(&mut app.world, Vec2::ZERO, 1.);
+    step(&mut app, 2);
+
+    set_input(&mut app, Vec2::new(0.5, 0.5), true);
+    step(&mut app, 2);
+    assert_eq!(dragged_body(&app), Some(body));
+    assert_eq!(mouse_joint_count(&mut app), 1);
+
+    set_input(&mut app, Vec2::new(5.5, 0.5), true);
+    step(&mut app, 120);
+    let position = app.world.get::<b2Body>(body).unwrap().position;
+    assert!(
+        position.distance(Vec2::new(5., 0.)) < 0.1,
+        "body was dragged to {position} instead of (5, 0)"
+    );
+
+    set_input(&mut app, Vec2::new(5.5, 0.5), false);
+    step(&mut app, 2);
+    assert_eq!(dragged_body(&app), None);
+    assert_eq!(mouse_joint_count(&mut app), 0);
+}
+
+#[test]
+fn pressing_on_empty_space_picks_nothing() {
+    let mut app = drag_app();
+    spawn_box(&mut app.world, Vec2::ZERO, 1.);
+    step(&mut app, 2);
+
+    set_input(&mut app, Vec2::new(10., 10.), true);
+    step(&mut app, 2);
+    assert_eq!(dragged_body(&app), None);
+
+    // moving onto the body while still holding the button doesn't grab it
+    set_input(&mut app, Vec2::ZERO, true);
+    step(&mut app, 2);
+    assert_eq!(dragged_body(&app), None);
+    assert_eq!(mouse_joint_count(&mut app), 0);
+}
+
+#[test]
+fn picks_the_most_recently_spawned_of_overlapping_bodies() {
+    let mut app = drag_app();
+    spawn_box(&mut app.world, Vec2::ZERO, 1.);
+    let (top_body, _) = spawn_box(&mut app.world, Vec2::ZERO, 1.);
+    step(&mut app, 2);
+
+    set_input(&mut app, Vec2::ZERO, true);
+    step(&mut app, 1);
+    assert_eq!(dragged_body(&app), Some(top_body));
+}
+
+#[test]
+fn picks_the_highest_priority_of_overlapping_bodies() {
+    let mut app = drag_app();
+    let (top_body, _) = spawn_box(&mut app.world, Vec2::ZERO, 1.);
+    app.world.entity_mut(top_body).insert(DragPriority(1));
+    spawn_box(&mut app.world, Vec2::ZERO, 1.);
+    step(&mut app, 2);
+
+    set_input(&mut app, Vec2::ZERO, true);
+    step(&mut app, 1);
+    assert_eq!(dragged_body(&app), Some(top_body));
+}
@@ -0,0 +1,113 @@
+//! Gear joints coupling revolute joints, and their cleanup when a referenced joint goes away.
+
+mod common;
+
+use bevy::ecs::system::EntityCommand;
+use bevy::prelude::*;
+
+use bevy_liquidfun::dynamics::{
+    b2Body, b2BodyType, b2DistanceJointDef, b2GearJointDef, b2RevoluteJointDef,
+    CreateDistanceJoint, CreateGearJoint, CreateRevoluteJoint,
+};
+
+use common::*;
+
+/// Two wheels on revolute joints to a static body, returned as `(wheels, joints)`.
+fn spawn_wheels(world: &mut World) -> ([Entity; 2], [Entity; 2]) {
+    let ground = spawn_body(world, b2BodyType::Static, Vec2::ZERO);
+    let wheels = [-2., 2.].map(|x| spawn_box(world, Vec2::new(x, 0.), 0.5).0);
+    let joints = wheels.map(|wheel| {
+        let position = world.get::<b2Body>(wheel).unwrap().position;
+        let def = b2RevoluteJointDef {
+            local_anchor_a: position,
+            ..default()
+        };
+        let joint = world.spawn_empty().id();
+        CreateRevoluteJoint::new(ground, wheel, false, &def).apply(joint, world);
+        joint
+    });
+    (wheels, joints)
+}
+
+fn spawn_gear(world: &mut World, joint_a: Entity, joint_b: Entity) -> Entity {
+    let gear = world.spawn_empty().id();
+    CreateGearJoint::new(joint_a, joint_b, false, &b2GearJointDef::default()).apply(gear, world);
+    gear
+}
+
+#[test]
+fn gear_joint_couples_the_rotation_of_its_joints() {
+    let mut app = physics_app(Vec2::ZERO);
+    let (wheels, joints) = spawn_wheels(&mut app.world);
+    spawn_gear(&mut app.world, joints[0], joints[1]);
+    app.update();
+
+    app.world
+        .get_mut::<b2Body>(wheels[0])
+        .unwrap()
+        .angular_velocity = 1.;
+    step(&mut app, 30);
+    let angle_a = app.world.get::<b2Body>(wheels[0]).unwrap().angle;
+    let angle_b = app.world.get::<b2Body>(wheels[1]).unwrap().angle;
+    assert!(angle_a.abs() > 0.1, "wheel a only turned to {angle_a}");
+    assert!(
+        (angle_a + angle_b).abs() < 0.01,
+        "wheels turned to {angle_a} and {angle_b}"
+    );
+}
+
+#[test]
+fn gear_joint_is_despawned_with_a_referenced_joint() {
+    let mut app = physics_app(Vec2::ZERO);
+    let (_, joints) = spawn_wheels(&mut app.world);
+    let gear = spawn_gear(&mut app.world, joints[0], joints[1]);
+    step(&mut app, 2);
+    assert!(app.world.get_entity(gear).is_some());
+
+    app.world.despawn(joints[0]);
+    step(&mut app, 2);
+    assert!(app.world.get_entity(gear).is_none());
+    assert!(app.world.get_entity(joints[1]).is_some());
+}
+
+#[test]
+fn gear_joint_is_despawned_with_a_body_of_a_referenced_joint() {
+    let mut app = physics_app(Vec2::ZERO);
+    let (wheels, joints) = spawn_wheels(&mut app.world);
+    let gear = spawn_gear(&mut app.world, joints[0], joints[1]);
+    step(&mut app, 2);
+
+    app.world.despawn(wheels[1]);
+    step(&mut app, 2);
+    assert!(app.world.get_entity(gear).is_none());
+}
+
+#[test]
+fn gear_joint_referencing_other_joint_types_is_skipped() {
+    let mut app = physics_app(Vec2::ZERO);
+    let (wheels, joints) = spawn_wheels(&mut app.world);
+    let distance_joint = app.world.spawn_empty().id();
+    let def = b2DistanceJointDef {
+        length: 4.,
+        min_length: 4.,
+        max_length: 4.,
+        ..default()
+    };
+    CreateDistanceJoint::new(wheels[0], wheels[1], false, &def)
+        .apply(distance_joint, &mut app.world);
+    spawn_gear(&mut app.world, joints[0], distance_joint);
+    step(&mut app, 2);
+
+    app.world.despawn(distance_joint);
+    step(&mut app, 2);
+}
+
+#[test]
+fn gear_joint_referencing_missing_joints_is_skipped() {
+    let mut app = physics_app(Vec2::ZERO);
+    let (_, joints) = spawn_wheels(&mut app.world);
+    let not_a_joint = app.world.spawn_empty().id();
+    let gear = spawn_gear(&mut app.world, joints[0], not_a_joint);
+    step(&mut app, 2);
+    assert!(app.world.get_entity(gear).is_some());
+}
@@ -0,0 +1,70 @@
+//! Destroying joints when their entity, their components or either of their bodies go away.
+
+mod common;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::dynamics::{b2Body, b2DistanceJoint, b2Joint};
+
+use common::*;
+
+/// Lets the pendulum settle and returns `(anchor, bob, joint)`.
+fn settled_pendulum(app: &mut App) -> (Entity, Entity, Entity) {
+    let pendulum = spawn_pendulum(&mut app.world);
+    step(app, 30);
+    assert!(!bob_fell(app, pendulum.1));
+    pendulum
+}
+
+fn bob_fell(app: &mut App, bob: Entity) -> bool {
+    step(app, 60);
+    app.world.get::<b2Body>(bob).unwrap().position.y < -PENDULUM_LENGTH - 2.
+}
+
+#[test]
+fn despawning_the_joint_entity_destroys_the_joint() {
+    let mut app = physics_app(GRAVITY);
+    let (_, bob, joint) = settled_pendulum(&mut app);
+
+    app.world.despawn(joint);
+    assert!(bob_fell(&mut app, bob));
+}
+
+#[test]
+fn removing_the_b2_joint_component_destroys_the_joint() {
+    let mut app = physics_app(GRAVITY);
+    let (_, bob, joint) = settled_pendulum(&mut app);
+
+    app.world.entity_mut(joint).remove::<b2Joint>();
+    assert!(bob_fell(&mut app, bob));
+}
+
+#[test]
+fn removing_the_typed_joint_component_destroys_the_joint() {
+    let mut app = physics_app(GRAVITY);
+    let (_, bob, joint) = settled_pendulum(&mut app);
+
+    app.world.entity_mut(joint).remove::<b2DistanceJoint>();
+    assert!(bob_fell(&mut app, bob));
+    assert!(app.world.get_entity(joint).is_some());
+}
+
+#[test]
+fn despawning_body_a_despawns_the_joint() {
+    let mut app = physics_app(GRAVITY);
+    let (anchor, bob, joint) = settled_pendulum(&mut app);
+
+    app.world.despawn(anchor);
+    assert!(bob_fell(&mut app, bob));
+    assert!(app.world.get_entity(joint).is_none());
+}
+
+#[test]
+fn despawning_body_b_despawns_the_joint() {
+    let mut app = physics_app(GRAVITY);
+    let (_, bob, joint) = settled_pendulum(&mut app);
+
+    app.world.despawn(bob);
+    step(&mut app, 2);
+    assert!(app.world.get_entity(joint).is_none());
+}
@@ -0,0 +1,86 @@
+//! One-way platforms letting bodies pass from below and land from above.
+
+mod common;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::collision::b2Shape;
+use bevy_liquidfun::dynamics::{b2Body, b2BodyType, OneWayPlatform};
+
+use common::*;
+
+const PLATFORM_HEIGHT: f32 = 5.;
+
+fn platform_app() -> App {
+    let mut app = physics_app(GRAVITY);
+    let platform = spawn_body(
+        &mut app.world,
+        b2BodyType::Static,
+        Vec2::new(0., PLATFORM_HEIGHT),
+    );
+    let shape = b2Shape::EdgeTwoSided {
+        v1: Vec2::new(-5., 0.),
+        v2: Vec2::new(5., 0.),
+    };
+    let platform_fixture = spawn_fixture(&mut app.world, platform, shape, 0.);
+    app.world
+        .entity_mut(platform_fixture)
+        .insert(OneWayPlatform::default());
+    app
+}
+
+#[test]
+fn bodies_from_above_land_on_the_platform() {
+    let mut app = platform_app();
+    let (body, _) = spawn_box(&mut app.world, Vec2::new(0., PLATFORM_HEIGHT + 3.), 0.5);
+
+    step(&mut app, 180);
+    let position = app.world.get::<b2Body>(body).unwrap().position;
+    assert!(
+        (position.y - (PLATFORM_HEIGHT + 0.5)).abs() < 0.05,
+        "body ended up at {position}"
+    );
+}
+
+#[test]
+fn bodies_from_below_pass_through_and_land_on_top() {
+    let mut app = platform_app();
+    let (body, _) = spawn_box(&mut app.world, Vec2::ZERO, 0.5);
+    app.update();
+    app.world.get_mut::<b2Body>(body).unwrap().linear_velocity = Vec2::new(0., 15.);
+
+    let mut highest = f32::MIN;
+    for _ in 0..300 {
+        app.update();
+        highest = highest.max(app.world.get::<b2Body>(body).unwrap().position.y);
+    }
+    assert!(
+        highest > PLATFORM_HEIGHT + 3.,
+        "body only rose to {highest}"
+    );
+    let position = app.world.get::<b2Body>(body).unwrap().position;
+    assert!(
+        (position.y - (PLATFORM_HEIGHT + 0.5)).abs() < 0.05,
+        "body ended up at {position}"
+    );
+}
+
+#[test]
+fn bodies_from_below_are_blocked_without_the_component() {
+    let mut app = platform_app();
+    let mut platforms = app.world.query_filtered::<Entity, With<OneWayPlatform>>();
+    let platform_fixture = platforms.single(&app.world);
+    app.world
+        .entity_mut(platform_fixture)
+        .remove::<OneWayPlatform>();
+    let (body, _) = spawn_box(&mut app.world, Vec2::ZERO, 0.5);
+    app.update();
+    app.world.get_mut::<b2Body>(body).unwrap().linear_velocity = Vec2::new(0., 15.);
+
+    let mut highest = f32::MIN;
+    for _ in 0..120 {
+        app.update();
+        highest = highest.max(app.world.get::<b2Body>(body).unwrap().position.y);
+    }
+    assert!(highest < PLATFORM_HEIGHT, "body rose to {highest}");
+}
@@ -0,0 +1,79 @@
+//! Begin and end events between particles and fixtures.
+
+mod common;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::dynamics::{b2Body, b2ParticleBodyBeginEvent, b2ParticleBodyEndEvent};
+use bevy_liquidfun::particles::{
+    b2ParticleDef, b2ParticleFlags, b2ParticleSystem, b2ParticleSystemDef,
+};
+
+use common::*;
+
+fn spawn_particle(world: &mut World, flags: b2ParticleFlags, position: Vec2) -> Entity {
+    let mut particle_system = b2ParticleSystem::new(&b2ParticleSystemDef {
+        radius: 0.1,
+        ..default()
+    });
+    particle_system.queue_particle_for_creation(&b2ParticleDef {
+        flags,
+        position,
+        velocity: Vec2::ZERO,
+        lifetime: 0.,
+    });
+    world.spawn(particle_system).id()
+}
+
+#[test]
+fn particle_landing_and_leaving_sends_begin_and_end() {
+    let mut app = physics_app(GRAVITY);
+    let (ground, ground_fixture) = spawn_ground(&mut app.world, 0.);
+    let particle_system = spawn_particle(
+        &mut app.world,
+        b2ParticleFlags::FixtureContactListenerParticle,
+        Vec2::new(0., 1.),
+    );
+
+    let mut begin_events = Vec::new();
+    for _ in 0..60 {
+        app.update();
+        begin_events.extend(events::<b2ParticleBodyBeginEvent>(&app));
+    }
+    assert_eq!(begin_events.len(), 1);
+    let begin_event = begin_events[0];
+    assert_eq!(begin_event.particle_system, particle_system);
+    assert_eq!(begin_event.contact.fixture, ground_fixture);
+    assert_eq!(begin_event.contact.body, ground);
+    assert!(events::<b2ParticleBodyEndEvent>(&app).is_empty());
+
+    // pull the ground away from under the resting particle
+    app.world.get_mut::<b2Body>(ground).unwrap().position = Vec2::new(0., -10.);
+    let mut end_events = Vec::new();
+    for _ in 0..5 {
+        app.update();
+        end_events.extend(events::<b2ParticleBodyEndEvent>(&app));
+    }
+    assert_eq!(end_events.len(), 1);
+    let end_event = end_events[0];
+    assert_eq!(end_event.particle_system, particle_system);
+    assert_eq!(end_event.particle_index, begin_event.contact.particle_index);
+    assert_eq!(end_event.fixture, ground_fixture);
+    assert_eq!(end_event.body, ground);
+}
+
+#[test]
+fn particles_without_the_listener_flag_send_no_events() {
+    let mut app = physics_app(GRAVITY);
+    spawn_ground(&mut app.world, 0.);
+    spawn_particle(
+        &mut app.world,
+        b2ParticleFlags::WaterParticle,
+        Vec2::new(0., 1.),
+    );
+
+    for _ in 0..60 {
+        app.update();
+        assert!(events::<b2ParticleBodyBeginEvent>(&app).is_empty());
+    }
+}
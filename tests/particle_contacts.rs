@@ -0,0 +1,72 @@
+//! Begin and end events between particles of the same system.
+
+mod common;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::dynamics::{b2ParticleParticleBeginEvent, b2ParticleParticleEndEvent};
+use bevy_liquidfun::particles::{
+    b2ParticleDef, b2ParticleFlags, b2ParticleSystem, b2ParticleSystemDef,
+};
+
+use common::*;
+
+/// Two touching particles flying apart, returned as the particle system entity.
+fn spawn_separating_particles(world: &mut World, flags: b2ParticleFlags) -> Entity {
+    let mut particle_system = b2ParticleSystem::new(&b2ParticleSystemDef {
+        radius: 0.1,
+        ..default()
+    });
+    for direction in [-1., 1.] {
+        particle_system.queue_particle_for_creation(&b2ParticleDef {
+            flags,
+            position: Vec2::new(direction * 0.05, 0.),
+            velocity: Vec2::new(direction * 2., 0.),
+            lifetime: 0.,
+        });
+    }
+    world.spawn(particle_system).id()
+}
+
+#[test]
+fn separating_particles_send_begin_and_end() {
+    let mut app = physics_app(Vec2::ZERO);
+    let particle_system = spawn_separating_particles(
+        &mut app.world,
+        b2ParticleFlags::ParticleContactListenerParticle,
+    );
+
+    let mut begin_events = Vec::new();
+    let mut end_events = Vec::new();
+    for _ in 0..30 {
+        app.update();
+        begin_events.extend(events::<b2ParticleParticleBeginEvent>(&app));
+        end_events.extend(events::<b2ParticleParticleEndEvent>(&app));
+    }
+
+    assert_eq!(begin_events.len(), 1);
+    assert_eq!(end_events.len(), 1);
+    let begin_event = begin_events[0];
+    let end_event = end_events[0];
+    assert_eq!(begin_event.particle_system, particle_system);
+    assert_eq!(end_event.particle_system, particle_system);
+
+    let mut begun_pair = [begin_event.contact.index_a, begin_event.contact.index_b];
+    let mut ended_pair = [end_event.particle_index_a, end_event.particle_index_b];
+    begun_pair.sort();
+    ended_pair.sort();
+    assert_eq!(begun_pair, [0, 1]);
+    assert_eq!(ended_pair, [0, 1]);
+}
+
+#[test]
+fn particles_without_the_listener_flag_send_no_events() {
+    let mut app = physics_app(Vec2::ZERO);
+    spawn_separating_particles(&mut app.world, b2ParticleFlags::WaterParticle);
+
+    for _ in 0..30 {
+        app.update();
+        assert!(events::<b2ParticleParticleBeginEvent>(&app).is_empty());
+        assert!(events::<b2ParticleParticleEndEvent>(&app).is_empty());
+    }
+}
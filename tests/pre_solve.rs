@@ -0,0 +1,68 @@
+//! Pre-solve hooks disabling contacts and setting their tangent speed.
+
+mod common;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::dynamics::{b2Body, b2PreSolveContact, b2World};
+
+use common::*;
+
+fn touches(contact: &b2PreSolveContact, fixture: Entity) -> bool {
+    contact.fixture_a() == fixture || contact.fixture_b() == fixture
+}
+
+#[test]
+fn disabled_contacts_let_bodies_fall_through() {
+    let mut app = physics_app(GRAVITY);
+    let (_, ground) = spawn_ground(&mut app.world, 0.);
+    let (body, _) = spawn_box(&mut app.world, Vec2::new(0., 2.), 0.5);
+    app.world
+        .non_send_resource_mut::<b2World>()
+        .add_pre_solve_hook(move |contact: &mut b2PreSolveContact| {
+            if touches(contact, ground) {
+                contact.set_enabled(false);
+            }
+        });
+
+    step(&mut app, 120);
+    let position = app.world.get::<b2Body>(body).unwrap().position;
+    assert!(position.y < -2., "body stopped at {position}");
+}
+
+#[test]
+fn tangent_speed_moves_resting_bodies_along_the_surface() {
+    let mut app = physics_app(GRAVITY);
+    let (_, ground) = spawn_ground(&mut app.world, 0.);
+    let (body, _) = spawn_box(&mut app.world, Vec2::new(0., 0.5), 0.5);
+    app.world
+        .non_send_resource_mut::<b2World>()
+        .add_pre_solve_hook(move |contact: &mut b2PreSolveContact| {
+            if touches(contact, ground) {
+                contact.set_tangent_speed(2.);
+            }
+        });
+
+    step(&mut app, 120);
+    let body = app.world.get::<b2Body>(body).unwrap();
+    assert!(body.position.y > 0.4, "body sank to {}", body.position);
+    assert!(
+        body.linear_velocity.x.abs() > 1.5,
+        "body only moves with {}",
+        body.linear_velocity
+    );
+}
+
+#[test]
+fn hooks_without_changes_keep_contacts_solid() {
+    let mut app = physics_app(GRAVITY);
+    spawn_ground(&mut app.world, 0.);
+    let (body, _) = spawn_box(&mut app.world, Vec2::new(0., 2.), 0.5);
+    app.world
+        .non_send_resource_mut::<b2World>()
+        .add_pre_solve_hook(|contact: &mut b2PreSolveContact| assert!(contact.is_enabled()));
+
+    step(&mut app, 120);
+    let position = app.world.get::<b2Body>(body).unwrap().position;
+    assert!((position.y - 0.5).abs() < 0.05, "body rests at {position}");
+}
@@ -0,0 +1,63 @@
+//! Spawning ropes and removing them through their root entity.
+
+mod common;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::collision::b2AABB;
+use bevy_liquidfun::dynamics::{
+    b2Body, b2BodyType, b2Fixture, b2Joint, b2World, RopeBuilder, RopeJointType,
+};
+
+use common::*;
+
+fn fixtures_in_world(app: &mut App) -> Vec<Entity> {
+    let aabb = b2AABB {
+        lower_bound: Vec2::splat(-100.),
+        upper_bound: Vec2::splat(100.),
+    };
+    app.world
+        .non_send_resource_mut::<b2World>()
+        .query_aabb(&aabb)
+}
+
+fn assert_root_despawn_removes_rope(joint_type: RopeJointType) {
+    let mut app = physics_app(GRAVITY);
+    let anchor = spawn_body(&mut app.world, b2BodyType::Static, Vec2::new(0., 10.));
+    let rope = with_commands(&mut app, |commands| {
+        RopeBuilder::new(Vec2::new(-5., 10.), Vec2::new(5., 10.))
+            .segment_count(8)
+            .joint_type(joint_type)
+            .attach_start(anchor, Vec2::new(-5., 0.))
+            .spawn(commands)
+    });
+    step(&mut app, 10);
+    assert_eq!(rope.segments().len(), 8);
+    assert_eq!(count::<b2Body>(&mut app), 9);
+    assert_eq!(count::<b2Fixture>(&mut app), 8);
+    assert_eq!(count::<b2Joint>(&mut app), 8);
+    assert_eq!(fixtures_in_world(&mut app).len(), 8);
+
+    app.world.entity_mut(rope.root()).despawn_recursive();
+    step(&mut app, 10);
+    assert_eq!(count::<b2Body>(&mut app), 1);
+    assert_eq!(count::<b2Fixture>(&mut app), 0);
+    assert_eq!(count::<b2Joint>(&mut app), 0);
+    assert_eq!(fixtures_in_world(&mut app), vec![]);
+    assert!(app
+        .world
+        .get::<b2Body>(anchor)
+        .unwrap()
+        .fixtures()
+        .is_empty());
+}
+
+#[test]
+fn despawning_the_root_removes_a_revolute_rope() {
+    assert_root_despawn_removes_rope(RopeJointType::Revolute);
+}
+
+#[test]
+fn despawning_the_root_removes_a_distance_rope() {
+    assert_root_despawn_removes_rope(RopeJointType::Distance { max_length: 0.1 });
+}
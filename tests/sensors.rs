@@ -0,0 +1,179 @@
+//! Sensor enter and exit events, their filtering and their order within a frame.
+
+mod common;
+
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::collision::b2Shape;
+use bevy_liquidfun::dynamics::{
+    b2Body, b2BodyType, b2Filter, b2Fixture, b2FixtureDef, SensorEnterEvent, SensorExitEvent,
+    SensorFilter,
+};
+
+use common::*;
+
+#[derive(Debug, PartialEq)]
+enum Visit {
+    Enter(Entity),
+    Exit(Entity),
+}
+
+/// The visits of the last update, listing enters before exits since they are separate events.
+fn visits(app: &App) -> Vec<Visit> {
+    let enters = events::<SensorEnterEvent>(app)
+        .into_iter()
+        .map(|event| Visit::Enter(event.visitor));
+    let exits = events::<SensorExitEvent>(app)
+        .into_iter()
+        .map(|event| Visit::Exit(event.visitor));
+    enters.chain(exits).collect()
+}
+
+fn spawn_sensor(
+    world: &mut World,
+    body_type: b2BodyType,
+    position: Vec2,
+    shape: b2Shape,
+) -> Entity {
+    let body = spawn_body(world, body_type, position);
+    let fixture_def = b2FixtureDef {
+        is_sensor: true,
+        ..b2FixtureDef::new(shape, 1.)
+    };
+    world.spawn(b2Fixture::new(body, &fixture_def)).id()
+}
+
+fn spawn_falling_visitor(world: &mut World, x: f32, category: u16) -> Entity {
+    let body = spawn_body(world, b2BodyType::Dynamic, Vec2::new(x, 10.));
+    let fixture_def = b2FixtureDef {
+        filter: b2Filter {
+            category,
+            ..default()
+        },
+        ..b2FixtureDef::new(b2Shape::create_box(0.25, 0.25), 1.)
+    };
+    world.spawn(b2Fixture::new(body, &fixture_def)).id()
+}
+
+/// Lets the visitors fall through a wide sensor and returns every visit in frame order.
+fn fall_through_sensor(app: &mut App) -> Vec<Visit> {
+    let mut all_visits = Vec::new();
+    for _ in 0..180 {
+        app.update();
+        all_visits.extend(visits(app));
+    }
+    all_visits
+}
+
+#[test]
+fn falling_body_enters_and_exits_sensor() {
+    let mut app = physics_app(GRAVITY);
+    spawn_sensor(
+        &mut app.world,
+        b2BodyType::Static,
+        Vec2::new(0., 5.),
+        b2Shape::create_box(5., 1.),
+    );
+    let visitor = spawn_falling_visitor(&mut app.world, 0., 0x0001);
+
+    assert_eq!(
+        fall_through_sensor(&mut app),
+        vec![Visit::Enter(visitor), Visit::Exit(visitor)]
+    );
+}
+
+#[test]
+fn sensor_filter_ignores_other_categories() {
+    let mut app = physics_app(GRAVITY);
+    let sensor = spawn_sensor(
+        &mut app.world,
+        b2BodyType::Static,
+        Vec2::new(0., 5.),
+        b2Shape::create_box(5., 1.),
+    );
+    app.world
+        .entity_mut(sensor)
+        .insert(SensorFilter::with_categories(0x0002));
+    spawn_falling_visitor(&mut app.world, -2., 0x0001);
+    let accepted_visitor = spawn_falling_visitor(&mut app.world, 2., 0x0002);
+
+    assert_eq!(
+        fall_through_sensor(&mut app),
+        vec![
+            Visit::Enter(accepted_visitor),
+            Visit::Exit(accepted_visitor)
+        ]
+    );
+}
+
+#[derive(Component)]
+struct Player;
+
+#[test]
+fn sensor_filter_requires_component_on_fixture_or_body() {
+    let mut app = physics_app(GRAVITY);
+    let sensor = spawn_sensor(
+        &mut app.world,
+        b2BodyType::Static,
+        Vec2::new(0., 5.),
+        b2Shape::create_box(5., 1.),
+    );
+    app.world
+        .entity_mut(sensor)
+        .insert(SensorFilter::default().require_component::<Player>());
+    spawn_falling_visitor(&mut app.world, -2., 0x0001);
+    let player = spawn_falling_visitor(&mut app.world, 2., 0x0001);
+    let player_body = app.world.get::<b2Fixture>(player).unwrap().body();
+    app.world.entity_mut(player_body).insert(Player);
+
+    assert_eq!(
+        fall_through_sensor(&mut app),
+        vec![Visit::Enter(player), Visit::Exit(player)]
+    );
+}
+
+/// A rod spinning almost a quarter turn per step sweeps in and out of a small sensor next to
+/// its pivot on every other step, so a frame of three steps sees it enter, exit and re-enter.
+#[test]
+fn reentering_within_a_frame_keeps_the_visit_active() {
+    let mut app = physics_app(Vec2::ZERO);
+    set_steps_per_update(&mut app, 3);
+
+    // contacts need a dynamic body, and the rod is kinematic
+    spawn_sensor(
+        &mut app.world,
+        b2BodyType::Dynamic,
+        Vec2::new(2., 0.),
+        b2Shape::Circle {
+            radius: 0.1,
+            position: Vec2::ZERO,
+        },
+    );
+    let rod_body = spawn_body(&mut app.world, b2BodyType::Kinematic, Vec2::ZERO);
+    app.world
+        .get_mut::<b2Body>(rod_body)
+        .unwrap()
+        .angular_velocity = 0.495 * PI / TIME_STEP;
+    let rod = spawn_fixture(&mut app.world, rod_body, b2Shape::create_box(3., 0.1), 1.);
+
+    // the first update only creates the bodies
+    step(&mut app, 2);
+    assert_eq!(
+        visits(&app),
+        vec![Visit::Enter(rod), Visit::Enter(rod), Visit::Exit(rod)]
+    );
+
+    // stop the rod inside the sensor, where it still is after re-entering
+    let mut body = app.world.get_mut::<b2Body>(rod_body).unwrap();
+    body.angular_velocity = 0.;
+    body.angle = PI;
+    app.update();
+    assert_eq!(visits(&app), vec![]);
+
+    // the visit is still active, so turning the rod away has to report the exit
+    app.world.get_mut::<b2Body>(rod_body).unwrap().angle = 0.5 * PI;
+    app.update();
+    assert_eq!(visits(&app), vec![Visit::Exit(rod)]);
+}
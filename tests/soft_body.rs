@@ -0,0 +1,94 @@
+//! Spawning soft bodies and removing them through their root entity.
+
+mod common;
+
+use bevy::prelude::*;
+
+use bevy_liquidfun::collision::b2AABB;
+use bevy_liquidfun::dynamics::{
+    b2Body, b2Fixture, b2Joint, b2World, SoftBodyBuilder, SoftBodyShape,
+};
+
+use common::*;
+
+fn fixtures_in_world(app: &mut App) -> Vec<Entity> {
+    let aabb = b2AABB {
+        lower_bound: Vec2::splat(-100.),
+        upper_bound: Vec2::splat(100.),
+    };
+    app.world
+        .non_send_resource_mut::<b2World>()
+        .query_aabb(&aabb)
+}
+
+fn assert_root_despawn_removes_soft_body(shape: SoftBodyShape, node_count: usize) {
+    let mut app = physics_app(GRAVITY);
+    let (ground, _) = spawn_ground(&mut app.world, 0.);
+    let soft_body = with_commands(&mut app, |commands| {
+        SoftBodyBuilder::new(shape).pressure(1.).spawn(commands)
+    });
+    step(&mut app, 10);
+    assert_eq!(soft_body.nodes().len(), node_count);
+    assert_eq!(count::<b2Body>(&mut app), node_count + 1);
+    assert_eq!(count::<b2Fixture>(&mut app), node_count + 1);
+    assert!(count::<b2Joint>(&mut app) > 0);
+
+    app.world.entity_mut(soft_body.root()).despawn_recursive();
+    step(&mut app, 10);
+    assert_eq!(count::<b2Body>(&mut app), 1);
+    assert_eq!(count::<b2Fixture>(&mut app), 1);
+    assert_eq!(count::<b2Joint>(&mut app), 0);
+    let ground_fixtures = app.world.get::<b2Body>(ground).unwrap().fixtures().len();
+    assert_eq!(fixtures_in_world(&mut app).len(), ground_fixtures);
+}
+
+#[test]
+fn despawning_the_root_removes_a_ring() {
+    let shape = SoftBodyShape::Ring {
+        center: Vec2::new(0., 5.),
+        radius: 2.,
+        node_count: 12,
+    };
+    assert_root_despawn_removes_soft_body(shape, 12);
+}
+
+#[test]
+fn despawning_the_root_removes_a_grid() {
+    let shape = SoftBodyShape::Grid {
+        center: Vec2::new(0., 5.),
+        size: Vec2::new(4., 2.),
+        columns: 5,
+        rows: 3,
+    };
+    assert_root_despawn_removes_soft_body(shape, 15);
+}
+
+/// Rings with fewer than five nodes link some node pairs through both neighbor offsets.
+#[test]
+fn small_rings_link_each_node_pair_once() {
+    let mut app = physics_app(Vec2::ZERO);
+    let shape = SoftBodyShape::Ring {
+        center: Vec2::ZERO,
+        radius: 1.,
+        node_count: 4,
+    };
+    with_commands(&mut app, |commands| {
+        SoftBodyBuilder::new(shape).spawn(commands)
+    });
+    step(&mut app, 2);
+
+    let mut links: Vec<(Entity, Entity)> = app
+        .world
+        .query::<&b2Joint>()
+        .iter(&app.world)
+        .map(|joint| {
+            let (a, b) = (*joint.body_a(), *joint.body_b());
+            (a.min(b), a.max(b))
+        })
+        .collect();
+    let link_count = links.len();
+    links.sort();
+    links.dedup();
+    assert_eq!(links.len(), link_count);
+    assert_eq!(link_count, 6);
+}